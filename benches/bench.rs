@@ -1,48 +1,97 @@
-#![feature(test)]
-
 extern crate bip39;
-extern crate test;
+extern crate criterion;
 
-use test::Bencher;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 
-#[bench]
-fn validate(b: &mut Bencher) {
-    let phrase =
-        "silly laptop awake length nature thunder category claim reveal supply attitude drip";
+const PHRASE_12: &str =
+    "silly laptop awake length nature thunder category claim reveal supply attitude drip";
+const PHRASE_24: &str = "always guess retreat devote warm poem giraffe thought prize ready maple daughter girl feel clay silent lemon bracket abstract basket toe tiny sword world";
+
+const ENGLISH: &str = include_str!("../src/langs/english.txt");
 
-    b.iter(|| {
-        let _ = Mnemonic::validate(phrase, Language::English);
+fn validate(c: &mut Criterion) {
+    c.bench_function("validate", |b| {
+        b.iter(|| Mnemonic::validate(black_box(PHRASE_12), Language::English))
     });
 }
 
-#[bench]
-fn from_entropy(b: &mut Bencher) {
-    let phrase =
-        "silly laptop awake length nature thunder category claim reveal supply attitude drip";
-    let m = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+fn from_phrase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_phrase");
+
+    for &(words, phrase) in &[(12, PHRASE_12), (24, PHRASE_24)] {
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(BenchmarkId::from_parameter(words), phrase, |b, phrase| {
+            b.iter(|| Mnemonic::from_phrase(black_box(phrase), Language::English).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn from_entropy(c: &mut Criterion) {
+    let m = Mnemonic::from_phrase(PHRASE_12, Language::English).unwrap();
     let entropy = m.entropy();
 
-    b.iter(|| {
-        let _ = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+    c.bench_function("from_entropy", |b| {
+        b.iter(|| Mnemonic::from_entropy(black_box(entropy), Language::English).unwrap())
     });
 }
 
-#[bench]
-fn new_mnemonic(b: &mut Bencher) {
-    b.iter(|| {
-        let _ = Mnemonic::new(MnemonicType::Words12, Language::English);
+fn new_mnemonic(c: &mut Criterion) {
+    c.bench_function("new_mnemonic", |b| {
+        b.iter(|| Mnemonic::new(MnemonicType::Words12, Language::English).unwrap())
     });
 }
 
-#[bench]
-fn new_seed(b: &mut Bencher) {
-    let phrase =
-        "silly laptop awake length nature thunder category claim reveal supply attitude drip";
-    let m = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+fn new_seed(c: &mut Criterion) {
+    let m = Mnemonic::from_phrase(PHRASE_12, Language::English).unwrap();
+
+    c.bench_function("new_seed", |b| b.iter(|| Seed::new(black_box(&m), "").unwrap()));
+}
+
+/// Compares the strategies available for word -> index lookups, the crate uses the hash map
+fn wordlist_lookup(c: &mut Criterion) {
+    let words: Vec<&str> = ENGLISH.split_whitespace().collect();
+    let wordmap = Language::English.wordmap();
+    let queries: Vec<&str> = PHRASE_24.split(' ').collect();
 
-    b.iter(|| {
-        let _ = Seed::new(&m, "");
+    let mut group = c.benchmark_group("wordlist_lookup");
+
+    group.throughput(Throughput::Elements(queries.len() as u64));
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for query in &queries {
+                black_box(words.iter().position(|word| word == query));
+            }
+        })
+    });
+    group.bench_function("binary", |b| {
+        b.iter(|| {
+            for query in &queries {
+                black_box(words.binary_search(query).ok());
+            }
+        })
     });
+    group.bench_function("hash", |b| {
+        b.iter(|| {
+            for query in &queries {
+                black_box(wordmap.contains_word(query));
+            }
+        })
+    });
+
+    group.finish();
 }
+
+criterion_group!(
+    benches,
+    validate,
+    from_phrase,
+    from_entropy,
+    new_mnemonic,
+    new_seed,
+    wordlist_lookup
+);
+criterion_main!(benches);
@@ -0,0 +1,173 @@
+use failure::Error;
+
+use crate::mnemonic::Mnemonic;
+use crate::seed::Seed;
+
+/// A serializable backup of a [`Mnemonic`][Mnemonic] and what is known about its
+/// [`Seed`][Seed], for wallet backup files
+///
+/// The backup holds the phrase and language of the mnemonic, whether the seed was derived with
+/// a password, and only if explicitly included with
+/// [`MnemonicBackup::with_seed()`][MnemonicBackup::with_seed()], the seed itself, serialized as
+/// with [`Seed::expose()`][Seed::expose()]. The
+/// password is never part of it. Deserializing validates the mnemonic again, like
+/// deserializing a [`Mnemonic`][Mnemonic] on its own does.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, MnemonicBackup, Language, Seed};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+/// let seed = Seed::new(&mnemonic, "hidden").unwrap();
+///
+/// let json = serde_json::to_string(&MnemonicBackup::new(&mnemonic, &seed)).unwrap();
+/// let backup: MnemonicBackup = serde_json::from_str(&json).unwrap();
+///
+/// assert!(backup.has_password());
+/// assert_eq!(backup.restore_seed("hidden").unwrap().as_bytes(), seed.as_bytes());
+/// ```
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [Seed]: ./seed/type.Seed.html
+/// [MnemonicBackup::with_seed()]: ./struct.MnemonicBackup.html#method.with_seed
+/// [Seed::expose()]: ./seed/struct.GenericSeed.html#method.expose
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MnemonicBackup {
+    mnemonic: Mnemonic,
+    has_password: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", serialize_with = "serialize_seed")]
+    seed: Option<Seed>,
+}
+
+impl MnemonicBackup {
+    /// Create a backup of `mnemonic`, recording whether `seed` was derived with a password but
+    /// leaving the seed out
+    pub fn new(mnemonic: &Mnemonic, seed: &Seed) -> Self {
+        MnemonicBackup {
+            mnemonic: mnemonic.clone(),
+            has_password: seed.has_password(),
+            seed: None,
+        }
+    }
+
+    /// Create a backup of `mnemonic` including `seed`
+    ///
+    /// Anyone reading the backup then gets the seed without needing the password, so only use
+    /// this for backups that are as well protected as the seed itself.
+    pub fn with_seed(mnemonic: &Mnemonic, seed: &Seed) -> Self {
+        MnemonicBackup {
+            seed: Some(seed.clone()),
+            ..MnemonicBackup::new(mnemonic, seed)
+        }
+    }
+
+    /// The backed up mnemonic
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.mnemonic
+    }
+
+    /// Whether the seed was derived with a non-empty password
+    pub fn has_password(&self) -> bool {
+        self.has_password
+    }
+
+    /// The seed, if it was included in the backup
+    ///
+    /// Once deserialized, its [`has_password()`][Seed::has_password()] is always `false`, use
+    /// [`MnemonicBackup::has_password()`][MnemonicBackup::has_password()] instead.
+    ///
+    /// [Seed::has_password()]: ./seed/struct.GenericSeed.html#method.has_password
+    /// [MnemonicBackup::has_password()]: ./struct.MnemonicBackup.html#method.has_password
+    pub fn seed(&self) -> Option<&Seed> {
+        self.seed.as_ref()
+    }
+
+    /// Derive the seed of the backed up mnemonic again, with `password`
+    ///
+    /// The same as [`Seed::new()`][Seed::new()], and fails like it. A wrong password silently
+    /// gives another seed, as it always does with BIP39; compare with
+    /// [`MnemonicBackup::seed()`][MnemonicBackup::seed()] when the seed was included.
+    ///
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    /// [MnemonicBackup::seed()]: ./struct.MnemonicBackup.html#method.seed
+    pub fn restore_seed(&self, password: &str) -> Result<Seed, Error> {
+        Seed::new(&self.mnemonic, password)
+    }
+}
+
+/// Serialize the included seed with its real bytes, see [`Seed::expose()`][Seed::expose()]
+///
+/// [Seed::expose()]: ./seed/struct.GenericSeed.html#method.expose
+fn serialize_seed<S>(seed: &Option<Seed>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+
+    seed.as_ref().map(Seed::expose).serialize(serializer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::language::Language;
+
+    const PHRASE: &str = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+    #[test]
+    fn backup_round_trip() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "hunter2").unwrap();
+
+        let json = serde_json::to_string(&MnemonicBackup::new(&mnemonic, &seed)).unwrap();
+        assert!(!json.contains("seed"));
+        assert!(!json.contains("hunter2"));
+
+        let backup: MnemonicBackup = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(backup.mnemonic(), &mnemonic);
+        assert!(backup.has_password());
+        assert!(backup.seed().is_none());
+        assert_eq!(backup.restore_seed("hunter2").unwrap().as_bytes(), seed.as_bytes());
+    }
+
+    #[test]
+    fn backup_with_seed() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "").unwrap();
+
+        let json = serde_json::to_string(&MnemonicBackup::with_seed(&mnemonic, &seed)).unwrap();
+        assert!(json.contains(&format!("\"seed\":{{\"bytes\":\"{:x}\"}}", seed)));
+
+        let backup: MnemonicBackup = serde_json::from_str(&json).unwrap();
+
+        assert!(!backup.has_password());
+        assert!(backup.seed().unwrap().ct_eq(&seed));
+        assert!(backup.restore_seed("").unwrap().ct_eq(&seed));
+
+        let binary = bincode::serialize(&backup).unwrap();
+        let backup: MnemonicBackup = bincode::deserialize(&binary).unwrap();
+        assert!(backup.seed().unwrap().ct_eq(&seed));
+    }
+
+    #[test]
+    fn backup_revalidated() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "").unwrap();
+
+        let json = serde_json::to_string(&MnemonicBackup::new(&mnemonic, &seed)).unwrap();
+        let tampered = json.replace("vibrant", "zoo");
+
+        assert!(serde_json::from_str::<MnemonicBackup>(&tampered).is_err());
+
+        let short_seed = json.replace("false", "false,\"seed\":{\"bytes\":\"00\"}");
+        let err = serde_json::from_str::<MnemonicBackup>(&short_seed).unwrap_err();
+        assert!(err.to_string().contains(&ErrorKind::InvalidSeedLength(1).to_string()));
+
+        let redacted = json.replace("false", "false,\"seed\":\"<redacted>\"");
+        assert!(serde_json::from_str::<MnemonicBackup>(&redacted).is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Deterministic child mnemonics following [BIP85][bip85]
+//!
+//! BIP85 derives any number of independent child mnemonics from a single master [`Seed`][Seed],
+//! so that backing up the master phrase backs up all of them. A child entropy is the HMAC-SHA512
+//! (keyed with `bip-entropy-from-k`) of the private key at the BIP32 path
+//! `m/83696968'/39'/{language}'/{words}'/{index}'`, truncated to the entropy size of the child.
+//! All the steps of the path are hardened, so no elliptic curve operations are needed.
+//!
+//! [bip85]: https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+//! [Seed]: ./seed/type.Seed.html
+use failure::Error;
+use zeroize::Zeroize;
+use crate::crypto::hmac_sha512;
+use crate::error::ErrorKind;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::seed::Seed;
+
+/// The order of the secp256k1 curve, big-endian
+const CURVE_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+const HARDENED: u32 = 0x8000_0000;
+
+/// The first step of every BIP85 path, "BIPS" on a phone keypad
+const BIP85_PURPOSE: u32 = 83_696_968;
+
+/// The application number of BIP39 mnemonics
+const BIP85_APP_BIP39: u32 = 39;
+
+/// A BIP32 private key with its chain code
+struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    fn from_hmac(mut output: Vec<u8>) -> ExtendedKey {
+        let mut extended = ExtendedKey {
+            key: [0; 32],
+            chain_code: [0; 32],
+        };
+        extended.key.copy_from_slice(&output[..32]);
+        extended.chain_code.copy_from_slice(&output[32..]);
+        output.zeroize();
+
+        extended
+    }
+
+    /// The BIP32 master key of a seed
+    fn master(seed: &[u8]) -> ExtendedKey {
+        ExtendedKey::from_hmac(hmac_sha512(b"Bitcoin seed", seed))
+    }
+
+    /// Derive the hardened child `index'`
+    ///
+    /// BIP32 declares a child invalid if the tweak isn't below the curve order or the child key
+    /// is zero, which happens with a probability below 2^-127 and isn't handled here.
+    fn derive_hardened(&self, index: u32) -> ExtendedKey {
+        let mut data = Vec::with_capacity(37);
+        data.push(0);
+        data.extend_from_slice(&self.key);
+        data.extend_from_slice(&(index | HARDENED).to_be_bytes());
+
+        let mut child = ExtendedKey::from_hmac(hmac_sha512(&self.chain_code, &data));
+        data.zeroize();
+
+        child.key = add_mod_order(&child.key, &self.key);
+        child
+    }
+
+    /// The BIP85 entropy for the hardened `path`
+    fn derive_entropy(&self, path: &[u32]) -> Vec<u8> {
+        let key = path[1..]
+            .iter()
+            .fold(self.derive_hardened(path[0]), |key, &index| {
+                key.derive_hardened(index)
+            });
+
+        hmac_sha512(b"bip-entropy-from-k", &key.key)
+    }
+}
+
+impl Drop for ExtendedKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// `(a + b) mod n` for the curve order `n`, with `b` below `n`
+fn add_mod_order(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut sum = [0u8; 32];
+    let mut carry = 0u16;
+
+    for i in (0..32).rev() {
+        let digit = u16::from(a[i]) + u16::from(b[i]) + carry;
+        sum[i] = digit as u8;
+        carry = digit >> 8;
+    }
+
+    // a < 2^256 and b < n, so at most two subtractions are needed
+    while carry > 0 || sum >= CURVE_ORDER {
+        let mut borrow = 0i16;
+
+        for i in (0..32).rev() {
+            let mut digit = i16::from(sum[i]) - i16::from(CURVE_ORDER[i]) - borrow;
+            borrow = 0;
+            if digit < 0 {
+                digit += 256;
+                borrow = 1;
+            }
+            sum[i] = digit as u8;
+        }
+
+        carry -= borrow as u16;
+    }
+
+    sum
+}
+
+/// The language numbers BIP85 assigns to the BIP39 word lists
+fn language_code(lang: Language) -> Option<u32> {
+    match lang {
+        Language::English => Some(0),
+        #[cfg(feature = "japanese")]
+        Language::Japanese => Some(1),
+        #[cfg(feature = "korean")]
+        Language::Korean => Some(2),
+        #[cfg(feature = "spanish")]
+        Language::Spanish => Some(3),
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified => Some(4),
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional => Some(5),
+        #[cfg(feature = "french")]
+        Language::French => Some(6),
+        #[cfg(feature = "italian")]
+        Language::Italian => Some(7),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+impl Seed {
+    /// Derive the BIP85 child [`Mnemonic`][Mnemonic] number `index` of this master seed
+    ///
+    /// The same seed, language, mnemonic type and index always give the same child, which is
+    /// interoperable with other BIP85 implementations. Children with different parameters are
+    /// independent of each other, and revealing a child reveals nothing about the master seed.
+    ///
+    /// Returns an `Error` of kind:
+    ///
+    /// * `ErrorKind::InvalidWordLength` unless `mtype` is 12, 18 or 24 words, the lengths
+    ///   BIP85 defines,
+    /// * `ErrorKind::UnsupportedLanguage` for custom word lists, which have no BIP85 number,
+    /// * `ErrorKind::InvalidChildIndex` if `index` is 2^31 or above, as it can't be hardened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic, MnemonicType, Seed};
+    ///
+    /// let master = Mnemonic::from_phrase("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", Language::English).unwrap();
+    /// let seed = Seed::new(&master, "TREZOR").unwrap();
+    ///
+    /// let child = seed.derive_bip85_mnemonic(Language::English, MnemonicType::Words12, 0).unwrap();
+    ///
+    /// assert_eq!(child.entropy_hex(), "2b1d7c4f311137fa95f6302e64cdb885");
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn derive_bip85_mnemonic(
+        &self,
+        lang: Language,
+        mtype: MnemonicType,
+        index: u32,
+    ) -> Result<Mnemonic, Error> {
+        match mtype {
+            MnemonicType::Words12 | MnemonicType::Words18 | MnemonicType::Words24 => {}
+            _ => Err(ErrorKind::InvalidWordLength(mtype.word_count()))?,
+        }
+
+        let code = match language_code(lang) {
+            Some(code) => code,
+            None => Err(ErrorKind::UnsupportedLanguage(lang))?,
+        };
+
+        if index >= HARDENED {
+            Err(ErrorKind::InvalidChildIndex(index))?;
+        }
+
+        let path = [
+            BIP85_PURPOSE,
+            BIP85_APP_BIP39,
+            code,
+            mtype.word_count() as u32,
+            index,
+        ];
+        let mut entropy = ExtendedKey::master(self.as_bytes()).derive_entropy(&path);
+        let mnemonic = Mnemonic::from_entropy(&entropy[..mtype.entropy_bytes()], lang);
+        entropy.zeroize();
+
+        mnemonic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::from_hex;
+
+    /// The master key of the test vectors in BIP85, given there as
+    /// `xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb`
+    fn bip85_master() -> ExtendedKey {
+        let mut key = [0; 32];
+        let mut chain_code = [0; 32];
+        key.copy_from_slice(
+            &from_hex("3f15e5d852dc2e9ba5e9fe189a8dd2e1547badef5b563bbe6579fc6807d80ed9").unwrap(),
+        );
+        chain_code.copy_from_slice(
+            &from_hex("1b67969d1ec69bdfeeae43213da8460ba34b92d0788c8f7bfcfa44906e8a589c").unwrap(),
+        );
+
+        ExtendedKey { key, chain_code }
+    }
+
+    #[test]
+    fn bip85_test_vectors() {
+        let master = bip85_master();
+
+        assert_eq!(
+            master.derive_entropy(&[BIP85_PURPOSE, 0, 0]),
+            from_hex(
+                "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f0\
+                 0b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7"
+            )
+            .unwrap()
+        );
+
+        let vectors = [
+            (12, "6250b68daf746d12a24d58b4787a714b"),
+            (18, "938033ed8b12698449d4bbca3c853c66b293ea1b1ce9d9dc"),
+            (24, "ae131e2312cdc61331542efe0d1077bac5ea803adf24b313a4f0e48e9c51f37f"),
+        ];
+
+        for &(words, entropy) in vectors.iter() {
+            let derived = master.derive_entropy(&[BIP85_PURPOSE, BIP85_APP_BIP39, 0, words, 0]);
+
+            assert_eq!(&derived[..entropy.len() / 2], &from_hex(entropy).unwrap()[..]);
+        }
+    }
+
+    #[test]
+    fn bip85_from_seed() {
+        let master = Mnemonic::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            Language::English,
+        )
+        .unwrap();
+        let seed = Seed::new(&master, "TREZOR").unwrap();
+
+        let child = seed
+            .derive_bip85_mnemonic(Language::English, MnemonicType::Words24, 1)
+            .unwrap();
+        assert_eq!(
+            child.entropy_hex(),
+            "550d89668b66e64026a770b5d9a339e66c954e5e8194ed79585b5078c81adb06"
+        );
+
+        #[cfg(feature = "japanese")]
+        {
+            let child = seed
+                .derive_bip85_mnemonic(Language::Japanese, MnemonicType::Words18, 7)
+                .unwrap();
+            assert_eq!(child.language(), Language::Japanese);
+            assert_eq!(
+                child.entropy_hex(),
+                "f86e974ae94e07413d1b537de0c39db73b7d40118bb33512"
+            );
+        }
+    }
+
+    #[test]
+    fn bip85_invalid_parameters() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "").unwrap();
+
+        let kind = |result: Result<Mnemonic, Error>| {
+            result.unwrap_err().downcast::<ErrorKind>().unwrap()
+        };
+
+        assert!(matches!(
+            kind(seed.derive_bip85_mnemonic(Language::English, MnemonicType::Words15, 0)),
+            ErrorKind::InvalidWordLength(15)
+        ));
+        assert!(matches!(
+            kind(seed.derive_bip85_mnemonic(Language::English, MnemonicType::Words12, HARDENED)),
+            ErrorKind::InvalidChildIndex(HARDENED)
+        ));
+    }
+
+    #[test]
+    fn bip85_add_mod_order() {
+        let mut max = CURVE_ORDER;
+        max[31] -= 1;
+        let mut one = [0; 32];
+        one[31] = 1;
+        let mut two = [0; 32];
+        two[31] = 2;
+
+        assert_eq!(add_mod_order(&max, &one), [0; 32]);
+        assert_eq!(add_mod_order(&max, &two), one);
+        assert_eq!(
+            &add_mod_order(&[0xff; 32], &max)[..],
+            &from_hex("000000000000000000000000000000014551231950b75fc4402da1732fc9bebd").unwrap()[..]
+        );
+    }
+}
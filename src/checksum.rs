@@ -0,0 +1,85 @@
+use crate::crypto::sha256_first_byte;
+
+/// The BIP39 checksum of some entropy: the first `ENT / 32` bits of its SHA-256 hash, where `ENT`
+/// is the length of the entropy in bits
+///
+/// # Example
+///
+/// ```
+/// use bip39::Checksum;
+///
+/// let entropy = [0u8; 16];
+///
+/// assert_eq!(Checksum::bit_width(entropy.len()), 4);
+/// assert_eq!(Checksum::compute(&entropy), 0b0011);
+/// ```
+pub struct Checksum;
+
+impl Checksum {
+    /// Compute the checksum of the entropy, returned in the least significant bits of the byte
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entropy doesn't have one of the valid BIP39 lengths: 16, 20, 24, 28 or 32
+    /// bytes.
+    pub fn compute(entropy: &[u8]) -> u8 {
+        let len = entropy.len();
+
+        assert!(
+            len.is_multiple_of(4) && (16..=32).contains(&len),
+            "invalid entropy length: {} bytes",
+            len
+        );
+
+        sha256_first_byte(entropy) >> (8 - Checksum::bit_width(len))
+    }
+
+    /// Get the number of checksum bits for entropy of `entropy_len` bytes
+    pub const fn bit_width(entropy_len: usize) -> usize {
+        entropy_len * 8 / 32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mnemonic_type::MnemonicType;
+
+    #[test]
+    fn bit_width() {
+        assert_eq!(Checksum::bit_width(16), 4);
+        assert_eq!(Checksum::bit_width(20), 5);
+        assert_eq!(Checksum::bit_width(24), 6);
+        assert_eq!(Checksum::bit_width(28), 7);
+        assert_eq!(Checksum::bit_width(32), 8);
+
+        for &mtype in &[MnemonicType::Words12, MnemonicType::Words18, MnemonicType::Words24] {
+            assert_eq!(
+                Checksum::bit_width(mtype.entropy_bytes()),
+                mtype.checksum_bits() as usize
+            );
+        }
+    }
+
+    #[test]
+    fn compute() {
+        // Checksums from the last words of the Trezor test vectors
+        assert_eq!(Checksum::compute(&[0x00; 16]), 0x3);
+        assert_eq!(Checksum::compute(&[0x7f; 16]), 0x8);
+        assert_eq!(Checksum::compute(&[0xff; 32]), 0xaf);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid entropy length")]
+    fn compute_invalid_length() {
+        Checksum::compute(&[0; 36]);
+    }
+
+    #[test]
+    fn compute_invalid_length_in_range() {
+        for &len in &[4, 12, 17, 31] {
+            let result = std::panic::catch_unwind(|| Checksum::compute(&vec![0; len]));
+            assert!(result.is_err(), "{} bytes", len);
+        }
+    }
+}
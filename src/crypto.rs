@@ -2,16 +2,19 @@
 //! into a [`Seed`][Seed].
 //!
 //! [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-//! [Seed]: ../seed/struct.Seed.html
+//! [Seed]: ../seed/type.Seed.html
 //!
 
 extern crate rand;
-use self::rand::{thread_rng, RngCore};
-use hmac::Hmac;
+use self::rand::thread_rng;
+use failure::Error;
+use crate::error::ErrorKind;
+use crate::entropy_source::{fill_with_retries, EntropySource, RNG_RETRIES};
+use hmac::{Hmac, Mac};
 use sha2::Digest;
 
-const PBKDF2_ROUNDS: usize = 2048;
-const PBKDF2_BYTES: usize = 64;
+pub(crate) const PBKDF2_ROUNDS: usize = 2048;
+pub(crate) const PBKDF2_BYTES: usize = 64;
 
 /// SHA256 helper function, internal to the crate
 ///
@@ -19,25 +22,223 @@ pub(crate) fn sha256_first_byte(input: &[u8]) -> u8 {
     sha2::Sha256::digest(input).as_ref()[0]
 }
 
+/// SHA256 helper function returning the full digest, internal to the crate
+///
+pub(crate) fn sha256(input: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(input).into()
+}
+
+/// SHA256 helper hashing the concatenation of `parts`, without copying them into one buffer
+///
+pub(crate) fn sha256_parts(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = sha2::Sha256::new();
+
+    for part in parts {
+        hasher.input(part);
+    }
+
+    hasher.result().into()
+}
+
+/// HMAC-SHA512 helper, used to derive sub-seeds from a [`Seed`][Seed]
+///
+/// [Seed]: ../seed/type.Seed.html
+///
+pub(crate) fn hmac_sha512(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<sha2::Sha512>::new_varkey(key).expect("HMAC accepts keys of any size");
+    mac.input(data);
+
+    mac.result().code().to_vec()
+}
+
 /// Random byte generator, used to create new mnemonics
 ///
-pub(crate) fn gen_random_bytes(byte_length: usize) -> Vec<u8> {
-    let mut rng = thread_rng();
+pub(crate) fn gen_random_bytes(byte_length: usize) -> Result<Vec<u8>, Error> {
+    gen_random_bytes_with(&mut thread_rng(), byte_length)
+}
+
+/// Random byte generator using the operating system's RNG (`OsRng`) directly
+///
+pub(crate) fn gen_os_random_bytes(byte_length: usize) -> Result<Vec<u8>, Error> {
+    let mut rng = match self::rand::rngs::OsRng::new() {
+        Ok(rng) => rng,
+        Err(_) => Err(ErrorKind::RngFailure(1))?,
+    };
+
+    gen_random_bytes_with(&mut rng, byte_length)
+}
+
+/// Random byte generator using the supplied RNG, retrying transient failures
+///
+pub(crate) fn gen_random_bytes_with<E>(source: &mut E, byte_length: usize) -> Result<Vec<u8>, Error>
+where
+    E: EntropySource + ?Sized,
+{
     let mut bytes = vec![0u8; byte_length];
 
-    rng.fill_bytes(&mut bytes);
+    fill_with_retries(source, &mut bytes, RNG_RETRIES)?;
 
-    bytes
+    Ok(bytes)
 }
 /// PBKDF2 helper, used to generate [`Seed`][Seed] from [`Mnemonic`][Mnemonic]
 ///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-/// [Seed]: ../seed/struct.Seed.html
+/// [Seed]: ../seed/type.Seed.html
+///
+pub(crate) fn pbkdf2(input: &[u8], salt: &str) -> Result<Vec<u8>, Error> {
+    pbkdf2_rounds(input, salt, PBKDF2_ROUNDS)
+}
+
+/// Refuse an empty input, which a valid [`Mnemonic`][Mnemonic] never produces, rather than
+/// deriving a seed from the salt alone
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+///
+fn check_input(input: &[u8]) -> Result<(), Error> {
+    if input.is_empty() {
+        Err(ErrorKind::EmptyPhrase)?;
+    }
+
+    Ok(())
+}
+
+/// PBKDF2 helper filling `output`, used to derive seeds of any length
 ///
-pub(crate) fn pbkdf2(input: &[u8], salt: &str) -> Vec<u8> {
+pub(crate) fn pbkdf2_into(input: &[u8], salt: &str, output: &mut [u8]) -> Result<(), Error> {
+    check_input(input)?;
+
+    pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(input, salt.as_bytes(), PBKDF2_ROUNDS, output);
+
+    Ok(())
+}
+
+/// PBKDF2 helper with a custom number of rounds, used by the [`SeedBuilder`][SeedBuilder]
+///
+/// [SeedBuilder]: ../seed/struct.SeedBuilder.html
+///
+pub(crate) fn pbkdf2_rounds(input: &[u8], salt: &str, rounds: usize) -> Result<Vec<u8>, Error> {
+    pbkdf2_with(input, salt, rounds, Pbkdf2Hash::Sha512)
+}
+
+/// The hash function used for the HMAC of PBKDF2
+///
+/// BIP39 specifies HMAC-SHA512, `Sha256` is only there to recover seeds from non-standard forks.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pbkdf2Hash {
+    Sha512,
+    Sha256,
+}
+
+/// PBKDF2 helper with a custom number of rounds and hash function
+///
+/// Returns an `Error` of kind `ErrorKind::EmptyPhrase` on an empty input.
+///
+pub(crate) fn pbkdf2_with(
+    input: &[u8],
+    salt: &str,
+    rounds: usize,
+    hash: Pbkdf2Hash,
+) -> Result<Vec<u8>, Error> {
+    check_input(input)?;
+
     let mut seed = vec![0u8; PBKDF2_BYTES];
 
-    pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(input, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+    match hash {
+        Pbkdf2Hash::Sha512 => {
+            pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(input, salt.as_bytes(), rounds, &mut seed)
+        }
+        Pbkdf2Hash::Sha256 => {
+            pbkdf2::pbkdf2::<Hmac<sha2::Sha256>>(input, salt.as_bytes(), rounds, &mut seed)
+        }
+    }
+
+    Ok(seed)
+}
+
+/// PBKDF2 helper deriving one output per salt from the same input
+///
+/// The HMAC key setup for the input is only done once and shared between all the salts, this is
+/// used to derive several [`Seed`][Seed]s from the same [`Mnemonic`][Mnemonic].
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+/// [Seed]: ../seed/type.Seed.html
+///
+pub(crate) fn pbkdf2_many<S>(input: &[u8], salts: &[S]) -> Result<Vec<Vec<u8>>, Error>
+where
+    S: AsRef<str>,
+{
+    let prf = pbkdf2_prf(input)?;
+
+    let seeds = salts
+        .iter()
+        .map(|salt| {
+            let mut seed = vec![0u8; PBKDF2_BYTES];
+
+            pbkdf2_body(&prf, salt.as_ref().as_bytes(), PBKDF2_ROUNDS, &mut seed);
+
+            seed
+        })
+        .collect();
+
+    Ok(seeds)
+}
+
+/// The PRF of PBKDF2 keyed with `input`, to be shared between several
+/// [`pbkdf2_body()`][pbkdf2_body()] calls
+///
+/// [pbkdf2_body()]: ./fn.pbkdf2_body.html
+///
+pub(crate) fn pbkdf2_prf(input: &[u8]) -> Result<Hmac<sha2::Sha512>, Error> {
+    check_input(input)?;
+
+    Ok(Hmac::<sha2::Sha512>::new_varkey(input).expect("HMAC accepts keys of any size"))
+}
+
+/// The PBKDF2 computation for an already keyed PRF
+pub(crate) fn pbkdf2_body(prf: &Hmac<sha2::Sha512>, salt: &[u8], rounds: usize, output: &mut [u8]) {
+    for (i, chunk) in output.chunks_mut(64).enumerate() {
+        let mut mac = prf.clone();
+        mac.input(salt);
+        mac.input(&(i as u32 + 1).to_be_bytes());
+
+        let mut block = mac.result().code();
+
+        chunk.copy_from_slice(&block[..chunk.len()]);
+
+        for _ in 1..rounds {
+            let mut mac = prf.clone();
+            mac.input(&block);
+            block = mac.result().code();
+
+            for (out, byte) in chunk.iter_mut().zip(block.iter()) {
+                *out ^= byte;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pbkdf2_rejects_empty_input() {
+        let is_empty_phrase =
+            |err: Error| matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::EmptyPhrase);
+
+        assert!(is_empty_phrase(pbkdf2(b"", "mnemonic").unwrap_err()));
+        assert!(is_empty_phrase(pbkdf2_into(b"", "mnemonic", &mut [0; 64]).unwrap_err()));
+        assert!(is_empty_phrase(pbkdf2_with(b"", "", 1, Pbkdf2Hash::Sha256).unwrap_err()));
+        assert!(is_empty_phrase(pbkdf2_many(b"", &["mnemonic"]).unwrap_err()));
+        assert!(is_empty_phrase(pbkdf2_prf(b"").unwrap_err()));
+
+        assert_eq!(pbkdf2(b"phrase", "mnemonic").unwrap().len(), PBKDF2_BYTES);
+    }
 
-    seed
+    #[test]
+    fn sha256_parts_hashes_concatenation() {
+        assert_eq!(sha256_parts(&[b"os random", b"", b"extra"]), sha256(b"os randomextra"));
+        assert_eq!(sha256_parts(&[]), sha256(b""));
+    }
 }
@@ -0,0 +1,303 @@
+//! Sources of entropy for generating new mnemonics
+//!
+//! [`Mnemonic::from_source()`][Mnemonic::from_source()] takes anything implementing
+//! [`EntropySource`][EntropySource], so that generation isn't tied to a particular RNG: a
+//! hardware TRNG, a test fixture or any cryptographically secure `rand` RNG all work the same.
+//!
+//! [Mnemonic::from_source()]: ./mnemonic/struct.Mnemonic.html#method.from_source
+//! [EntropySource]: ./trait.EntropySource.html
+use failure::Error;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+use crate::error::ErrorKind;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+
+/// A source of the random bytes a new [`Mnemonic`][Mnemonic] is generated from
+///
+/// Implemented for every `rand` RNG that is `RngCore + CryptoRng`. Whatever the implementation,
+/// the security of the generated mnemonics is exactly that of the bytes it produces.
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+pub trait EntropySource {
+    /// Fill `buf` entirely with random bytes
+    fn fill(&mut self, buf: &mut [u8]);
+
+    /// Fill `buf` entirely with random bytes, or report that the source failed
+    ///
+    /// Generating functions call this and retry transient failures a few times before returning
+    /// an `Error` of kind `ErrorKind::RngFailure`. The default implementation calls
+    /// [`fill()`][EntropySource::fill()] and never fails.
+    ///
+    /// [EntropySource::fill()]: ./trait.EntropySource.html#tymethod.fill
+    fn try_fill(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.fill(buf);
+
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng> EntropySource for R {
+    fn fill(&mut self, buf: &mut [u8]) {
+        self.fill_bytes(buf);
+    }
+
+    fn try_fill(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        Ok(self.try_fill_bytes(buf)?)
+    }
+}
+
+/// The number of times a failing [`EntropySource`][EntropySource] is retried by default
+///
+/// [EntropySource]: ./trait.EntropySource.html
+pub(crate) const RNG_RETRIES: usize = 3;
+
+/// Fill `buf` from `source`, retrying up to `retries` times if it fails
+pub(crate) fn fill_with_retries<E>(
+    source: &mut E,
+    buf: &mut [u8],
+    retries: usize,
+) -> Result<(), Error>
+where
+    E: EntropySource + ?Sized,
+{
+    for _ in 0..=retries {
+        if source.try_fill(buf).is_ok() {
+            return Ok(());
+        }
+    }
+
+    // a failing source may have filled part of the buffer
+    buf.zeroize();
+
+    Err(ErrorKind::RngFailure(retries + 1))?
+}
+
+/// An [`EntropySource`][EntropySource] handing out predetermined bytes, in order
+///
+/// Meant for tests and reproducible examples: mnemonics generated from a known source are
+/// **not** secret and must never hold funds.
+///
+/// # Panics
+///
+/// [`fill()`][EntropySource::fill()] panics when the source doesn't have enough bytes left.
+///
+/// [EntropySource]: ./trait.EntropySource.html
+/// [EntropySource::fill()]: ./trait.EntropySource.html#tymethod.fill
+#[derive(Debug, Clone)]
+pub struct FixedSource {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl FixedSource {
+    /// Create a source handing out `bytes`
+    pub fn new(bytes: &[u8]) -> FixedSource {
+        FixedSource {
+            bytes: bytes.to_vec(),
+            position: 0,
+        }
+    }
+
+    /// The number of bytes not handed out yet
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+}
+
+impl EntropySource for FixedSource {
+    fn fill(&mut self, buf: &mut [u8]) {
+        assert!(
+            buf.len() <= self.remaining(),
+            "fixed entropy source exhausted: {} bytes requested, {} left",
+            buf.len(),
+            self.remaining()
+        );
+
+        buf.copy_from_slice(&self.bytes[self.position..self.position + buf.len()]);
+        self.position += buf.len();
+    }
+}
+
+impl Mnemonic {
+    /// Generates a new [`Mnemonic`][Mnemonic] from the bytes of an
+    /// [`EntropySource`][EntropySource]
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the source still fails after a few
+    /// retries, see [`Mnemonic::from_source_with_retries()`][Mnemonic::from_source_with_retries()]
+    /// to choose how many.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{FixedSource, Language, Mnemonic, MnemonicType};
+    ///
+    /// let mut source = FixedSource::new(&[0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84, 0x6A, 0x79]);
+    /// let mnemonic = Mnemonic::from_source(&mut source, MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.phrase());
+    ///
+    /// // any cryptographically secure RNG is an entropy source too
+    /// let mnemonic = Mnemonic::from_source(&mut rand::thread_rng(), MnemonicType::Words24, Language::English).unwrap();
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [EntropySource]: ./trait.EntropySource.html
+    /// [Mnemonic::from_source_with_retries()]: ./mnemonic/struct.Mnemonic.html#method.from_source_with_retries
+    pub fn from_source<E>(
+        source: &mut E,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Mnemonic, Error>
+    where
+        E: EntropySource + ?Sized,
+    {
+        Mnemonic::from_source_with_retries(source, mtype, lang, RNG_RETRIES)
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] from the bytes of an
+    /// [`EntropySource`][EntropySource], retrying up to `retries` times if it fails
+    ///
+    /// Sources failing transiently, such as a hardware RNG that is briefly busy, may need a larger
+    /// budget than [`Mnemonic::from_source()`][Mnemonic::from_source()] allows. Returns an
+    /// `Error` of kind `ErrorKind::RngFailure` with the number of attempts if every one failed.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [EntropySource]: ./trait.EntropySource.html
+    /// [Mnemonic::from_source()]: ./mnemonic/struct.Mnemonic.html#method.from_source
+    pub fn from_source_with_retries<E>(
+        source: &mut E,
+        mtype: MnemonicType,
+        lang: Language,
+        retries: usize,
+    ) -> Result<Mnemonic, Error>
+    where
+        E: EntropySource + ?Sized,
+    {
+        let mut entropy = vec![0u8; mtype.entropy_bytes()];
+        fill_with_retries(source, &mut entropy, retries)?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn from_fixed_source() {
+        let bytes: Vec<u8> = (0..48).collect();
+        let mut source = FixedSource::new(&bytes);
+
+        let first = Mnemonic::from_source(&mut source, MnemonicType::Words12, Language::English)
+            .unwrap();
+        let second = Mnemonic::from_source(&mut source, MnemonicType::Words24, Language::English)
+            .unwrap();
+
+        assert_eq!(first.entropy(), &bytes[..16]);
+        assert_eq!(second.entropy(), &bytes[16..]);
+        assert_eq!(source.remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed entropy source exhausted")]
+    fn from_exhausted_fixed_source() {
+        let mut source = FixedSource::new(&[0; 16]);
+
+        Mnemonic::from_source(&mut source, MnemonicType::Words15, Language::English).unwrap();
+    }
+
+    #[test]
+    fn from_rng_source() {
+        let mut a = StdRng::seed_from_u64(7);
+        let mut b = StdRng::seed_from_u64(7);
+
+        let mnemonic =
+            Mnemonic::from_source(&mut a, MnemonicType::Words18, Language::English).unwrap();
+        let expected =
+            Mnemonic::generate_many_with_rng(&mut b, 1, MnemonicType::Words18, Language::English)
+                .unwrap();
+
+        assert_eq!(mnemonic.phrase(), expected[0].phrase());
+    }
+
+    /// Fails `failures` times, then hands out bytes of 0x42
+    struct FlakySource {
+        failures: usize,
+        attempts: usize,
+    }
+
+    impl EntropySource for FlakySource {
+        fn fill(&mut self, _buf: &mut [u8]) {
+            unreachable!("generation must go through try_fill");
+        }
+
+        fn try_fill(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+            self.attempts += 1;
+
+            // fill part of the buffer before failing, like a source interrupted midway
+            buf[0] = 0x42;
+
+            if self.attempts <= self.failures {
+                Err(failure::err_msg("device busy"))
+            } else {
+                buf.iter_mut().for_each(|byte| *byte = 0x42);
+
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn from_flaky_source() {
+        let mut source = FlakySource { failures: RNG_RETRIES, attempts: 0 };
+        let mnemonic = Mnemonic::from_source(&mut source, MnemonicType::Words12, Language::English)
+            .unwrap();
+
+        assert_eq!(mnemonic.entropy(), &[0x42; 16][..]);
+        assert_eq!(source.attempts, RNG_RETRIES + 1);
+    }
+
+    #[test]
+    fn from_failing_source() {
+        let mut source = FlakySource { failures: RNG_RETRIES + 1, attempts: 0 };
+        let err = Mnemonic::from_source(&mut source, MnemonicType::Words12, Language::English)
+            .unwrap_err();
+
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::RngFailure(4)));
+        assert_eq!(source.attempts, RNG_RETRIES + 1);
+
+        let mut buf = [0u8; 16];
+        let mut source = FlakySource { failures: 10, attempts: 0 };
+        assert!(fill_with_retries(&mut source, &mut buf, 9).is_err());
+        assert_eq!(buf, [0; 16]);
+    }
+
+    #[test]
+    fn from_source_with_retries() {
+        let mut source = FlakySource { failures: 10, attempts: 0 };
+        let mnemonic = Mnemonic::from_source_with_retries(
+            &mut source,
+            MnemonicType::Words24,
+            Language::English,
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(mnemonic.entropy(), &[0x42; 32][..]);
+
+        let mut source = FlakySource { failures: 1, attempts: 0 };
+        let err = Mnemonic::from_source_with_retries(
+            &mut source,
+            MnemonicType::Words12,
+            Language::English,
+            0,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::RngFailure(1)));
+    }
+}
@@ -1,15 +1,207 @@
+use std::error::Error;
+use std::fmt;
+use crate::language::Language;
 use crate::mnemonic_type::MnemonicType;
 
-#[derive(Debug, Fail)]
+/// The kinds of errors returned by this crate, which can be recovered from a `failure::Error`
+/// with `downcast::<ErrorKind>()`
+///
+/// New variants are added as the crate grows, so the enum is `#[non_exhaustive]`: adding one
+/// is then not a breaking change, and matches outside the crate need a wildcard arm. The
+/// predicates such as [`ErrorKind::is_checksum_error()`][ErrorKind::is_checksum_error()] check
+/// common groups of variants without having to list them.
+///
+/// [ErrorKind::is_checksum_error()]: ./enum.ErrorKind.html#method.is_checksum_error
+#[derive(Debug)]
+#[non_exhaustive]
 pub enum ErrorKind {
-	#[fail(display = "invalid checksum")]
 	InvalidChecksum,
-	#[fail(display = "invalid word in phrase")]
 	InvalidWord,
-	#[fail(display = "invalid keysize: {}", _0)]
+	SuspiciousCharacters { index: usize, word: String },
 	InvalidKeysize(usize),
-	#[fail(display = "invalid number of words in phrase: {}", _0)]
 	InvalidWordLength(usize),
-	#[fail(display = "invalid entropy length {}bits for mnemonic type {:?}", _0, _1)]
 	InvalidEntropyLength(usize, MnemonicType),
+	AmbiguousPrefix(usize),
+	PrefixTooShort(usize),
+	InvalidWordlistLength(usize),
+	InvalidWordlistWord(usize),
+	DuplicateWordlistWord(usize),
+	UnsortedWordlist(usize),
+	InvalidHex(HexError),
+	InvalidSeedLength(usize),
+	InvalidDiceRoll(usize),
+	InvalidDiceRollCount(usize),
+	DiceRollsOutOfRange,
+	MixedLanguages { detected: Vec<Language> },
+	BufferTooSmall { needed: usize, len: usize },
+	UnexpectedWordCount { expected: MnemonicType, got: usize },
+	InvalidUtf8(usize),
+	IndexWordMismatch { position: usize },
+	SeedIsOneWay,
+	UnsupportedLanguage(Language),
+	InvalidChildIndex(u32),
+	RetryLimitExceeded(usize),
+	PasswordTooLong { len: usize, max: usize },
+	BrokenInvariant(&'static str),
+	LikelyMoneroSeed,
+	RngFailure(usize),
+	EmptyPhrase,
+	EntropyMismatch,
 }
+
+impl ErrorKind {
+	/// Whether the words of the phrase are valid, but the checksum doesn't match
+	///
+	/// # Example
+	///
+	/// ```
+	/// use bip39::{ErrorKind, Language, Mnemonic};
+	///
+	/// let phrase = "park remain person kitchen mule spell knee armed position rail grid abandon";
+	/// let err = Mnemonic::from_phrase(phrase, Language::English).unwrap_err();
+	///
+	/// assert!(err.downcast::<ErrorKind>().unwrap().is_checksum_error());
+	/// ```
+	pub fn is_checksum_error(&self) -> bool {
+		matches!(self, ErrorKind::InvalidChecksum)
+	}
+
+	/// Whether a word of the phrase couldn't be matched to the word list
+	pub fn is_word_error(&self) -> bool {
+		matches!(
+			self,
+			ErrorKind::InvalidWord
+				| ErrorKind::SuspiciousCharacters { .. }
+				| ErrorKind::AmbiguousPrefix(_)
+				| ErrorKind::PrefixTooShort(_)
+				| ErrorKind::IndexWordMismatch { .. }
+				| ErrorKind::MixedLanguages { .. }
+		)
+	}
+
+	/// Whether the phrase, entropy or seed doesn't have a valid length
+	pub fn is_length_error(&self) -> bool {
+		matches!(
+			self,
+			ErrorKind::InvalidKeysize(_)
+				| ErrorKind::InvalidWordLength(_)
+				| ErrorKind::InvalidEntropyLength(..)
+				| ErrorKind::UnexpectedWordCount { .. }
+				| ErrorKind::InvalidSeedLength(_)
+		)
+	}
+}
+
+impl fmt::Display for ErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ErrorKind::InvalidChecksum => write!(f, "invalid checksum"),
+			ErrorKind::InvalidWord => write!(f, "invalid word in phrase"),
+			ErrorKind::SuspiciousCharacters { index, .. } => {
+				write!(f, "word at position {} contains suspicious look-alike characters", index)
+			}
+			ErrorKind::InvalidKeysize(size) => write!(f, "invalid keysize: {}", size),
+			ErrorKind::InvalidWordLength(count) => {
+				write!(f, "invalid number of words in phrase: {}", count)
+			}
+			ErrorKind::InvalidEntropyLength(bits, mtype) => {
+				write!(f, "invalid entropy length {}bits for mnemonic type {:?}", bits, mtype)
+			}
+			ErrorKind::AmbiguousPrefix(position) => {
+				write!(f, "word prefix at position {} matches multiple words", position)
+			}
+			ErrorKind::PrefixTooShort(position) => {
+				write!(f, "word prefix at position {} is too short", position)
+			}
+			ErrorKind::InvalidWordlistLength(len) => write!(f, "invalid word list length: {}", len),
+			ErrorKind::InvalidWordlistWord(index) => write!(
+				f,
+				"word list entry {} is empty, contains whitespace or isn't NFKD normalized",
+				index
+			),
+			ErrorKind::DuplicateWordlistWord(index) => {
+				write!(f, "word list entry {} is a duplicate", index)
+			}
+			ErrorKind::UnsortedWordlist(index) => write!(f, "word list entry {} is out of order", index),
+			ErrorKind::InvalidHex(_) => write!(f, "invalid hex string"),
+			ErrorKind::InvalidSeedLength(len) => write!(f, "invalid seed length: {} bytes", len),
+			ErrorKind::InvalidDiceRoll(index) => write!(f, "invalid dice roll at position {}", index),
+			ErrorKind::InvalidDiceRollCount(count) => write!(f, "invalid number of dice rolls: {}", count),
+			ErrorKind::DiceRollsOutOfRange => write!(f, "dice rolls exceed the entropy size"),
+			ErrorKind::MixedLanguages { detected } => {
+				write!(f, "phrase mixes words from multiple languages: {:?}", detected)
+			}
+			ErrorKind::BufferTooSmall { needed, len } => {
+				write!(f, "buffer of {} bytes is too small, {} bytes needed", len, needed)
+			}
+			ErrorKind::UnexpectedWordCount { expected, got } => write!(
+				f,
+				"expected a phrase of {} words, got {} words",
+				expected.word_count(),
+				got
+			),
+			ErrorKind::InvalidUtf8(index) => write!(f, "invalid UTF-8 at byte {}", index),
+			ErrorKind::IndexWordMismatch { position } => {
+				write!(f, "word at position {} doesn't match its index", position)
+			}
+			ErrorKind::SeedIsOneWay => write!(
+				f,
+				"a mnemonic can't be recovered from its seed, the seed derivation is one-way"
+			),
+			ErrorKind::UnsupportedLanguage(lang) => {
+				write!(f, "the {} word list isn't supported here", lang.name())
+			}
+			ErrorKind::InvalidChildIndex(index) => {
+				write!(f, "child index {} is too large to be hardened", index)
+			}
+			ErrorKind::RetryLimitExceeded(attempts) => {
+				write!(f, "no phrase satisfying the constraints found in {} attempts", attempts)
+			}
+			ErrorKind::PasswordTooLong { len, max } => {
+				write!(f, "password of {} bytes exceeds the limit of {} bytes", len, max)
+			}
+			ErrorKind::BrokenInvariant(invariant) => write!(f, "broken invariant: {}", invariant),
+			ErrorKind::LikelyMoneroSeed => {
+				write!(f, "this looks like a Monero seed, which isn't compatible with BIP39")
+			}
+			ErrorKind::RngFailure(attempts) => {
+				write!(f, "the random number generator failed {} times in a row", attempts)
+			}
+			ErrorKind::EmptyPhrase => write!(f, "refusing to derive a seed from an empty phrase"),
+			ErrorKind::EntropyMismatch => write!(f, "the entropy doesn't match the phrase"),
+		}
+	}
+}
+
+impl Error for ErrorKind {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			ErrorKind::InvalidHex(err) => Some(err),
+			_ => None,
+		}
+	}
+}
+
+/// The reason a hex string couldn't be decoded, available as the
+/// [`source()`](https://doc.rust-lang.org/std/error/trait.Error.html#method.source) of
+/// `ErrorKind::InvalidHex`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+	/// The string has an odd number of digits
+	OddLength(usize),
+	/// The character at the position isn't a hex digit
+	InvalidDigit { index: usize, digit: char },
+}
+
+impl fmt::Display for HexError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			HexError::OddLength(len) => write!(f, "odd number of hex digits: {}", len),
+			HexError::InvalidDigit { index, digit } => {
+				write!(f, "invalid hex digit {:?} at position {}", digit, index)
+			}
+		}
+	}
+}
+
+impl Error for HexError {}
@@ -0,0 +1,80 @@
+//! The BIP32 master key fingerprint of a seed, with the `fingerprint` feature
+//!
+//! Wallets label master keys with the first 4 bytes of the HASH160 (RIPEMD160 of SHA256) of the
+//! compressed public master key. The public key is computed with the `k256` secp256k1
+//! implementation, and nothing beyond the fingerprint of BIP32 is provided.
+use bitcoin_hashes::{hash160, Hash};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::SecretKey;
+use zeroize::Zeroize;
+use crate::crypto::hmac_sha512;
+use crate::seed::Seed;
+
+impl Seed {
+    /// Compute the BIP32 master key fingerprint of this seed
+    ///
+    /// This is the first 4 bytes of the HASH160 of the compressed public master key, which
+    /// wallets and PSBTs use to label and match master keys, e.g. `3442193e` for the seed of
+    /// the first BIP32 test vector. Only the fingerprint is computed, the keys themselves are
+    /// never exposed: use a BIP32 crate to derive keys.
+    ///
+    /// # Panics
+    ///
+    /// BIP32 declares seeds whose master key is zero or not below the curve order invalid, which
+    /// happens with a probability below 2^-127. This panics for such a seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// assert_eq!(seed.master_fingerprint(), [0x73, 0xc5, 0xda, 0x0a]);
+    /// ```
+    pub fn master_fingerprint(&self) -> [u8; 4] {
+        master_fingerprint(self.as_bytes())
+    }
+}
+
+fn master_fingerprint(seed: &[u8]) -> [u8; 4] {
+    let mut master = hmac_sha512(b"Bitcoin seed", seed);
+    let key = SecretKey::from_slice(&master[..32]);
+    master.zeroize();
+
+    let key = key.expect("the master key of a seed is a valid secp256k1 scalar");
+    let public = key.public_key().to_encoded_point(true);
+
+    let hash = hash160::Hash::hash(public.as_bytes()).to_byte_array();
+
+    let mut fingerprint = [0u8; 4];
+    fingerprint.copy_from_slice(&hash[..4]);
+    fingerprint
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::from_hex;
+
+    #[test]
+    fn fingerprint_bip32_test_vectors() {
+        let vectors = [
+            ("000102030405060708090a0b0c0d0e0f", [0x34, 0x42, 0x19, 0x3e]),
+            (
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                [0xbd, 0x16, 0xbe, 0xe5],
+            ),
+            (
+                "4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be",
+                [0x41, 0xd6, 0x3b, 0x50],
+            ),
+        ];
+
+        for &(seed, fingerprint) in vectors.iter() {
+            assert_eq!(master_fingerprint(&from_hex(seed).unwrap()), fingerprint, "{}", seed);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Importing a phrase with typos, scoring how confidently each word was recovered
+//!
+//! [`fuzzy_import()`][fuzzy_import()] corrects misspelled and abbreviated tokens to the closest
+//! words of a word list, and reports how certain every correction is, so that a UI can ask the
+//! user to confirm the corrected words before the phrase is used.
+//!
+//! [fuzzy_import()]: ./fn.fuzzy_import.html
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+
+/// The largest edit distance at which a token is still corrected to a word
+const MAX_DISTANCE: usize = 2;
+
+/// The confidence every word must reach for
+/// [`FuzzyResult::is_confident()`][FuzzyResult::is_confident()]
+///
+/// [FuzzyResult::is_confident()]: ./struct.FuzzyResult.html#method.is_confident
+const HIGH_CONFIDENCE: f64 = 0.75;
+
+/// How a single token was resolved by [`fuzzy_import()`][fuzzy_import()]
+///
+/// [fuzzy_import()]: ./fn.fuzzy_import.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyWord {
+    /// The word the token was resolved to, `None` if no word is close enough
+    pub word: Option<&'static str>,
+    /// How certain the resolution is, 1.0 for an exact match or a unique prefix of at least four
+    /// letters, less for a correction, and 0.0 if no word was found
+    pub confidence: f64,
+    /// Whether the word differs from the token, once normalized
+    pub corrected: bool,
+}
+
+/// The outcome of [`fuzzy_import()`][fuzzy_import()]
+///
+/// [fuzzy_import()]: ./fn.fuzzy_import.html
+#[derive(Debug, Clone)]
+pub struct FuzzyResult {
+    /// How each token was resolved, in order
+    pub words: Vec<FuzzyWord>,
+    /// The mnemonic made of the resolved words, if every token was resolved and the checksum
+    /// passes
+    pub mnemonic: Option<Mnemonic>,
+}
+
+impl FuzzyResult {
+    /// The number of tokens that were corrected to a different word
+    pub fn corrected_count(&self) -> usize {
+        self.words.iter().filter(|word| word.corrected).count()
+    }
+
+    /// Whether a valid mnemonic was recovered with every word resolved with high confidence
+    ///
+    /// Even then, corrected words should be confirmed by the user before the mnemonic is used.
+    pub fn is_confident(&self) -> bool {
+        self.mnemonic.is_some() && self.words.iter().all(|word| word.confidence >= HIGH_CONFIDENCE)
+    }
+}
+
+/// Resolve possibly misspelled or abbreviated tokens to the words of a phrase, scoring each
+/// resolution, for "we corrected 2 words, please confirm" flows
+///
+/// Every token is trimmed, lowercased and NFKD normalized, then resolved to:
+///
+/// - the same word, with a confidence of 1.0,
+/// - the word it is a prefix of, if it has at least four letters and no other word of `lang` starts
+///   with it, also with a confidence of 1.0,
+/// - otherwise the closest word within a Levenshtein distance of 2, with a confidence of
+///   `1 - distance / length`, divided by the number of words at the same distance.
+///
+/// The resolved words are then validated as a phrase, checksum included, and the resulting
+/// [`Mnemonic`][Mnemonic] is available in the returned [`FuzzyResult`][FuzzyResult]. A valid
+/// checksum makes a wrong correction unlikely, but not impossible (1 in 16 for 12 words).
+///
+/// # Example
+///
+/// ```
+/// use bip39::{fuzzy_import, Language};
+///
+/// let tokens = ["crop", "cash", "unable", "insane", "eight", "faith", "inflict", "route", "frame", "loud", "box", "vibrnt"];
+/// let result = fuzzy_import(&tokens, Language::English);
+///
+/// assert_eq!(result.corrected_count(), 1);
+/// assert_eq!(result.words[11].word, Some("vibrant"));
+/// assert!(result.is_confident());
+/// ```
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+/// [FuzzyResult]: ./struct.FuzzyResult.html
+pub fn fuzzy_import(tokens: &[&str], lang: Language) -> FuzzyResult {
+    let words: Vec<FuzzyWord> = tokens.iter().map(|token| resolve(token, lang)).collect();
+
+    let resolved: Option<Vec<&str>> = words.iter().map(|word| word.word).collect();
+    let mnemonic = resolved.and_then(|resolved| {
+        let phrase = zeroize::Zeroizing::new(resolved.join(" "));
+
+        Mnemonic::from_phrase(&phrase, lang).ok()
+    });
+
+    FuzzyResult { words, mnemonic }
+}
+
+fn resolve(token: &str, lang: Language) -> FuzzyWord {
+    let mut token: String = token.trim().to_lowercase().nfkd().collect();
+    let wordlist = lang.wordlist();
+
+    let resolved = if let Some(index) = lang.word_index(&token) {
+        let word = wordlist.get_word(index.into());
+
+        FuzzyWord { word: Some(word), confidence: 1.0, corrected: false }
+    } else {
+        let mut candidates = wordlist.iter_words_by_prefix(&token);
+
+        match (candidates.next(), candidates.next()) {
+            (Some(word), None) if token.nfc().count() >= 4 => {
+                FuzzyWord { word: Some(word), confidence: 1.0, corrected: true }
+            }
+            _ => closest(&token, lang),
+        }
+    };
+
+    token.zeroize();
+
+    resolved
+}
+
+/// The closest word to `token` within `MAX_DISTANCE` edits
+fn closest(token: &str, lang: Language) -> FuzzyWord {
+    let token: Vec<char> = token.chars().collect();
+
+    let mut best = None;
+    let mut best_distance = MAX_DISTANCE + 1;
+    let mut ties = 0;
+
+    for word in lang.wordlist().iter_words_by_prefix("") {
+        let distance = levenshtein(&token, word);
+
+        if distance < best_distance {
+            best = Some(word);
+            best_distance = distance;
+            ties = 1;
+        } else if distance == best_distance {
+            ties += 1;
+        }
+    }
+
+    match best {
+        Some(word) => {
+            let len = word.chars().count().max(token.len()) as f64;
+            let confidence = (1.0 - best_distance as f64 / len) / ties as f64;
+
+            FuzzyWord { word: Some(word), confidence, corrected: true }
+        }
+        None => FuzzyWord { word: None, confidence: 0.0, corrected: false },
+    }
+}
+
+/// The number of single character insertions, deletions and substitutions turning `a` into `b`
+fn levenshtein(a: &[char], b: &str) -> usize {
+    let mut row: Vec<usize> = (0..=a.len()).collect();
+
+    for (j, cb) in b.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+
+        for (i, &ca) in a.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[i + 1];
+            row[i + 1] = substitution.min(row[i] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[a.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PHRASE: [&str; 12] = [
+        "crop", "cash", "unable", "insane", "eight", "faith", "inflict", "route", "frame", "loud",
+        "box", "vibrant",
+    ];
+
+    #[test]
+    fn levenshtein_distance() {
+        let chars = |s: &str| s.chars().collect::<Vec<char>>();
+
+        assert_eq!(levenshtein(&chars(""), ""), 0);
+        assert_eq!(levenshtein(&chars("abc"), ""), 3);
+        assert_eq!(levenshtein(&chars(""), "abc"), 3);
+        assert_eq!(levenshtein(&chars("kitten"), "sitting"), 3);
+        assert_eq!(levenshtein(&chars("vibrnt"), "vibrant"), 1);
+        assert_eq!(levenshtein(&chars("été"), "ete"), 2);
+    }
+
+    #[test]
+    fn fuzzy_exact_and_misspelled() {
+        let mut tokens = PHRASE;
+        tokens[1] = "cassh";
+        let result = fuzzy_import(&tokens, Language::English);
+
+        let exact = FuzzyWord { word: Some("crop"), confidence: 1.0, corrected: false };
+        assert_eq!(result.words[0], exact);
+
+        // one deletion away from the five letters of the token
+        let corrected = FuzzyWord { word: Some("cash"), confidence: 0.8, corrected: true };
+        assert_eq!(result.words[1], corrected);
+        assert!(result.is_confident());
+
+        assert_eq!(result.corrected_count(), 1);
+        assert_eq!(result.mnemonic.unwrap().phrase(), PHRASE.join(" "));
+    }
+
+    #[test]
+    fn fuzzy_prefixes_and_case() {
+        let tokens = [
+            "CROP", " cash ", "unab", "insa", "eigh", "fait", "infl", "rout", "fram", "loud",
+            "box", "vibr",
+        ];
+        let result = fuzzy_import(&tokens, Language::English);
+
+        assert!(result.words.iter().all(|word| word.confidence == 1.0));
+        assert_eq!(result.corrected_count(), 8);
+        assert!(result.is_confident());
+        assert_eq!(result.mnemonic.unwrap().phrase(), PHRASE.join(" "));
+    }
+
+    #[test]
+    fn fuzzy_unrecoverable() {
+        let mut tokens = PHRASE;
+        tokens[3] = "qqqqqqqq";
+        let result = fuzzy_import(&tokens, Language::English);
+
+        assert_eq!(result.words[3], FuzzyWord { word: None, confidence: 0.0, corrected: false });
+        assert!(result.mnemonic.is_none());
+        assert!(!result.is_confident());
+
+        // every word is valid, but the checksum doesn't match
+        let mut tokens = PHRASE;
+        tokens.swap(0, 1);
+        let result = fuzzy_import(&tokens, Language::English);
+
+        assert_eq!(result.corrected_count(), 0);
+        assert!(result.mnemonic.is_none());
+    }
+}
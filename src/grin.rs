@@ -0,0 +1,82 @@
+//! Helpers following the conventions of Grin wallets
+//!
+//! A Grin wallet seed is 32 bytes of random entropy (stored encrypted in `wallet.seed`), and the
+//! recovery phrase shown to the user is the English BIP39 mnemonic for that entropy. Grin's
+//! keychain is derived from the wallet seed itself, not from a BIP39 PBKDF2 seed, so no
+//! [`Seed`][Seed] helper is provided: use [`Mnemonic::grin_wallet_seed()`][grin_wallet_seed].
+//!
+//! [Seed]: ../seed/type.Seed.html
+//! [grin_wallet_seed]: ../mnemonic/struct.Mnemonic.html#method.grin_wallet_seed
+use failure::Error;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+
+/// The length of the wallet seed generated by Grin wallets, in bytes
+pub const GRIN_WALLET_SEED_BYTES: usize = 32;
+
+impl Mnemonic {
+    /// Create the recovery phrase for a Grin wallet seed
+    ///
+    /// Grin only uses the English word list. The seed is usually
+    /// [`GRIN_WALLET_SEED_BYTES`][GRIN_WALLET_SEED_BYTES] long, resulting in 24 words, but any
+    /// valid entropy length is accepted, as Grin does when recovering a wallet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Mnemonic;
+    ///
+    /// let mnemonic = Mnemonic::from_grin_wallet_seed(&[0; 32]).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().count(), 24);
+    /// assert_eq!(mnemonic.grin_wallet_seed(), &[0; 32]);
+    /// ```
+    ///
+    /// [GRIN_WALLET_SEED_BYTES]: ../constant.GRIN_WALLET_SEED_BYTES.html
+    pub fn from_grin_wallet_seed(wallet_seed: &[u8]) -> Result<Mnemonic, Error> {
+        Mnemonic::from_entropy(wallet_seed, Language::English)
+    }
+
+    /// Parse a Grin recovery phrase, which is always in English
+    pub fn from_grin_phrase(phrase: &str) -> Result<Mnemonic, Error> {
+        Mnemonic::from_phrase(phrase, Language::English)
+    }
+
+    /// Get the Grin wallet seed encoded by this mnemonic, i.e. its entropy
+    pub fn grin_wallet_seed(&self) -> &[u8] {
+        self.entropy()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    /// The 24 word "legal winner" vector of the BIP39 reference test vectors: Grin's recovery
+    /// phrase is plain BIP39, so any entropy/phrase pair is also a wallet seed/phrase pair
+    const PHRASE: &str = "legal winner thank year wave sausage worth useful legal winner thank year \
+                          wave sausage worth useful legal winner thank year wave sausage worth title";
+
+    #[test]
+    fn grin_wallet_seed_round_trip() {
+        let wallet_seed = [0x7f; GRIN_WALLET_SEED_BYTES];
+
+        let mnemonic = Mnemonic::from_grin_wallet_seed(&wallet_seed).unwrap();
+        assert_eq!(mnemonic.phrase(), PHRASE);
+        assert_eq!(mnemonic.language(), Language::English);
+
+        let restored = Mnemonic::from_grin_phrase(PHRASE).unwrap();
+        assert_eq!(restored.grin_wallet_seed(), wallet_seed);
+    }
+
+    #[test]
+    fn grin_invalid_wallet_seed() {
+        let err = Mnemonic::from_grin_wallet_seed(&[0; 31]).unwrap_err();
+
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidKeysize(248)
+        ));
+    }
+}
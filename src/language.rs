@@ -1,7 +1,7 @@
 use crate::error::ErrorKind;
 use crate::util::{Bits, Bits11};
 use failure::Error;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub struct WordMap {
     inner: FxHashMap<&'static str, Bits11>,
@@ -9,6 +9,8 @@ pub struct WordMap {
 
 pub struct WordList {
     inner: Vec<&'static str>,
+    /// Whether `inner` is in byte order, which binary searches rely on
+    sorted: bool,
 }
 
 impl WordMap {
@@ -18,6 +20,10 @@ impl WordMap {
             None => Err(ErrorKind::InvalidWord)?,
         }
     }
+
+    pub fn contains_word(&self, word: &str) -> bool {
+        self.inner.contains_key(word)
+    }
 }
 
 impl WordList {
@@ -25,7 +31,27 @@ impl WordList {
         self.inner[bits.bits() as usize]
     }
 
+    /// Iterate over all the words starting with `prefix`, in word list order
+    ///
+    /// Unlike `get_words_by_prefix` this doesn't rely on the word list being sorted.
+    pub(crate) fn iter_words_by_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = &'static str> + 'a {
+        self.inner
+            .iter()
+            .cloned()
+            .filter(move |word| word.starts_with(prefix))
+    }
+
+    /// Get all the words starting with `prefix`
+    ///
+    /// This binary searches the word list, so it only works for the word lists that are sorted in
+    /// byte order (English, Italian, Korean and custom word lists). Debug builds panic when it is
+    /// used with another one.
     pub fn get_words_by_prefix(&self, prefix: &str) -> &[&'static str] {
+        debug_assert!(self.sorted, "prefix lookup on a word list that isn't sorted");
+
         let start = self.inner
             .binary_search(&prefix)
             .unwrap_or_else(|idx| idx);
@@ -37,21 +63,62 @@ impl WordList {
     }
 }
 
+/// Check that the words make a usable word list: exactly 2048 distinct words that are non-empty,
+/// free of whitespace and NFKD normalized, and in byte order if `sorted` is set
+fn validate_words<W: AsRef<str>>(words: &[W], sorted: bool) -> Result<(), ErrorKind> {
+    if words.len() != 2048 {
+        return Err(ErrorKind::InvalidWordlistLength(words.len()));
+    }
+
+    let mut seen = FxHashSet::default();
+
+    for (index, word) in words.iter().map(AsRef::as_ref).enumerate() {
+        if word.is_empty()
+            || word.contains(char::is_whitespace)
+            || !unicode_normalization::is_nfkd(word)
+        {
+            return Err(ErrorKind::InvalidWordlistWord(index));
+        }
+
+        if !seen.insert(word) {
+            return Err(ErrorKind::DuplicateWordlistWord(index));
+        }
+
+        if sorted && index > 0 && words[index - 1].as_ref() > word {
+            return Err(ErrorKind::UnsortedWordlist(index));
+        }
+    }
+
+    Ok(())
+}
+
 mod lazy {
     use super::{Bits11, WordList, WordMap};
     use once_cell::sync::Lazy;
 
     /// lazy generation of the word list
-    fn gen_wordlist(lang_words: &'static str) -> WordList {
+    ///
+    /// With the `debug-checks` feature the word list is validated once, when it is first used.
+    fn gen_wordlist(lang_words: &'static str, sorted: bool) -> WordList {
         let inner: Vec<_> = lang_words.split_whitespace().collect();
 
         debug_assert!(inner.len() == 2048, "Invalid wordlist length");
+        debug_assert!(
+            !sorted || inner.windows(2).all(|pair| pair[0] < pair[1]),
+            "word list isn't sorted"
+        );
 
-        WordList { inner }
+        if cfg!(feature = "debug-checks") {
+            if let Err(err) = super::validate_words(&inner, sorted) {
+                panic!("corrupt word list: {}", err);
+            }
+        }
+
+        WordList { inner, sorted }
     }
 
     /// lazy generation of the word map
-    fn gen_wordmap(wordlist: &WordList) -> WordMap {
+    pub(super) fn gen_wordmap(wordlist: &WordList) -> WordMap {
         let inner = wordlist
             .inner
             .iter()
@@ -62,29 +129,46 @@ mod lazy {
         WordMap { inner }
     }
 
+    /// The embedded, newline separated word lists
+    pub const TEXT_ENGLISH: &str = include_str!("langs/english.txt");
+    #[cfg(feature = "chinese-simplified")]
+    pub const TEXT_CHINESE_SIMPLIFIED: &str = include_str!("langs/chinese_simplified.txt");
+    #[cfg(feature = "chinese-traditional")]
+    pub const TEXT_CHINESE_TRADITIONAL: &str = include_str!("langs/chinese_traditional.txt");
+    #[cfg(feature = "french")]
+    pub const TEXT_FRENCH: &str = include_str!("langs/french.txt");
+    #[cfg(feature = "italian")]
+    pub const TEXT_ITALIAN: &str = include_str!("langs/italian.txt");
+    #[cfg(feature = "japanese")]
+    pub const TEXT_JAPANESE: &str = include_str!("langs/japanese.txt");
+    #[cfg(feature = "korean")]
+    pub const TEXT_KOREAN: &str = include_str!("langs/korean.txt");
+    #[cfg(feature = "spanish")]
+    pub const TEXT_SPANISH: &str = include_str!("langs/spanish.txt");
+
     pub static WORDLIST_ENGLISH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/english.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_ENGLISH, true));
     #[cfg(feature = "chinese-simplified")]
     pub static WORDLIST_CHINESE_SIMPLIFIED: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/chinese_simplified.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_CHINESE_SIMPLIFIED, false));
     #[cfg(feature = "chinese-traditional")]
     pub static WORDLIST_CHINESE_TRADITIONAL: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/chinese_traditional.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_CHINESE_TRADITIONAL, false));
     #[cfg(feature = "french")]
     pub static WORDLIST_FRENCH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/french.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_FRENCH, false));
     #[cfg(feature = "italian")]
     pub static WORDLIST_ITALIAN: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/italian.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_ITALIAN, true));
     #[cfg(feature = "japanese")]
     pub static WORDLIST_JAPANESE: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/japanese.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_JAPANESE, false));
     #[cfg(feature = "korean")]
     pub static WORDLIST_KOREAN: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/korean.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_KOREAN, true));
     #[cfg(feature = "spanish")]
     pub static WORDLIST_SPANISH: Lazy<WordList> =
-        Lazy::new(|| gen_wordlist(include_str!("langs/spanish.txt")));
+        Lazy::new(|| gen_wordlist(TEXT_SPANISH, false));
 
     pub static WORDMAP_ENGLISH: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_ENGLISH));
     #[cfg(feature = "chinese-simplified")]
@@ -105,16 +189,67 @@ mod lazy {
     pub static WORDMAP_SPANISH: Lazy<WordMap> = Lazy::new(|| gen_wordmap(&WORDLIST_SPANISH));
 }
 
+/// A word list registered at runtime with [`Language::register_custom()`][Language::register_custom()]
+///
+/// [Language::register_custom()]: ./enum.Language.html#method.register_custom
+#[cfg(feature = "dynamic-wordlists")]
+pub struct CustomLanguage {
+    name: &'static str,
+    wordlist: WordList,
+    wordmap: WordMap,
+}
+
+#[cfg(feature = "dynamic-wordlists")]
+impl CustomLanguage {
+    /// Get the name the word list was registered with
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(feature = "dynamic-wordlists")]
+impl std::fmt::Debug for CustomLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("CustomLanguage").field(&self.name).finish()
+    }
+}
+
+/// Two custom languages are the same if they were returned by the same registration
+#[cfg(feature = "dynamic-wordlists")]
+impl PartialEq for CustomLanguage {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+#[cfg(feature = "dynamic-wordlists")]
+impl Eq for CustomLanguage {}
+
+#[cfg(feature = "dynamic-wordlists")]
+impl std::hash::Hash for CustomLanguage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self as *const Self).hash(state)
+    }
+}
+
 /// The language determines which words will be used in a mnemonic phrase, but also indirectly
 /// determines the binary value of each word when a [`Mnemonic`][Mnemonic] is turned into a [`Seed`][Seed].
 ///
 /// These are not of much use right now, and may even be removed from the crate, as there is no
 /// official language specified by the standard except English.
 ///
+/// `Language::default()` is English, which is always compiled in, unlike the other languages
+/// which each have their own feature.
+///
+/// The enum is `#[non_exhaustive]` so that languages can be added without a breaking change,
+/// matches outside the crate need a wildcard arm.
+///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
-/// [Seed]: ./seed/struct.Seed.html
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+/// [Seed]: ./seed/type.Seed.html
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
 pub enum Language {
+    #[default]
     English,
     #[cfg(feature = "chinese-simplified")]
     ChineseSimplified,
@@ -130,9 +265,97 @@ pub enum Language {
     Korean,
     #[cfg(feature = "spanish")]
     Spanish,
+    /// A word list registered at runtime, see [`Language::register_custom()`][Language::register_custom()]
+    ///
+    /// Custom languages can't be serialized.
+    ///
+    /// [Language::register_custom()]: ./enum.Language.html#method.register_custom
+    #[cfg(feature = "dynamic-wordlists")]
+    #[serde(skip)]
+    Custom(&'static CustomLanguage),
 }
 
 impl Language {
+    /// Get all the languages compiled into the crate, in declaration order
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::English,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional,
+            #[cfg(feature = "french")]
+            Language::French,
+            #[cfg(feature = "italian")]
+            Language::Italian,
+            #[cfg(feature = "japanese")]
+            Language::Japanese,
+            #[cfg(feature = "korean")]
+            Language::Korean,
+            #[cfg(feature = "spanish")]
+            Language::Spanish,
+        ]
+    }
+
+    /// Register a word list supplied at runtime, returning a `Language` that can be used like any
+    /// other, e.g. with [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()] or
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// As a malformed word list would silently produce unrecoverable mnemonics, the words are
+    /// checked thoroughly, returning an `Error` of kind:
+    ///
+    /// * `ErrorKind::InvalidWordlistLength` if there aren't exactly 2048 words,
+    /// * `ErrorKind::InvalidWordlistWord` if a word is empty, contains whitespace or isn't NFKD
+    ///   normalized,
+    /// * `ErrorKind::DuplicateWordlistWord` if a word appears more than once,
+    /// * `ErrorKind::UnsortedWordlist` if the words aren't sorted.
+    ///
+    /// Registered word lists live for the rest of the program, so this should only be called
+    /// once per word list.
+    ///
+    /// Unlike the built-in languages, a custom language can't be serialized: serializing it, or
+    /// a [`Mnemonic`][Mnemonic] using it, returns an error, as there is no way to find the word
+    /// list again on deserialization. Serialize the phrase or the entropy instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Language, Mnemonic};
+    ///
+    /// let words = include_str!("langs/english.txt").split_whitespace().map(|w| w.to_uppercase()).collect();
+    /// let shouting = Language::register_custom("shouting", words).unwrap();
+    ///
+    /// let mnemonic = Mnemonic::from_entropy(&[0; 16], shouting).unwrap();
+    ///
+    /// assert!(mnemonic.phrase().starts_with("ABANDON ABANDON"));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    #[cfg(feature = "dynamic-wordlists")]
+    pub fn register_custom(name: &str, words: Vec<String>) -> Result<Language, Error> {
+        validate_words(&words, true)?;
+
+        let inner = words
+            .into_iter()
+            .map(|word| &*Box::leak(word.into_boxed_str()))
+            .collect();
+        let wordlist = WordList {
+            inner,
+            sorted: true,
+        };
+        let wordmap = lazy::gen_wordmap(&wordlist);
+
+        let custom = Box::leak(Box::new(CustomLanguage {
+            name: Box::leak(name.to_owned().into_boxed_str()),
+            wordlist,
+            wordmap,
+        }));
+
+        Ok(Language::Custom(custom))
+    }
+
     /// Construct a word list from its language code. Returns None
     /// if the language code is not valid or not supported.
     pub fn from_language_code(language_code: &str) -> Option<Self> {
@@ -156,6 +379,197 @@ impl Language {
         }
     }
 
+    /// Get the language of its byte in compact encodings, the reverse of
+    /// [`Language::to_u8()`][Language::to_u8()]
+    ///
+    /// Returns `None` for bytes that don't stand for a language, or for a language that isn't
+    /// compiled in.
+    ///
+    /// [Language::to_u8()]: ./enum.Language.html#method.to_u8
+    pub fn from_u8(n: u8) -> Option<Self> {
+        match n {
+            0 => Some(Language::English),
+            #[cfg(feature = "chinese-simplified")]
+            1 => Some(Language::ChineseSimplified),
+            #[cfg(feature = "chinese-traditional")]
+            2 => Some(Language::ChineseTraditional),
+            #[cfg(feature = "french")]
+            3 => Some(Language::French),
+            #[cfg(feature = "italian")]
+            4 => Some(Language::Italian),
+            #[cfg(feature = "japanese")]
+            5 => Some(Language::Japanese),
+            #[cfg(feature = "korean")]
+            6 => Some(Language::Korean),
+            #[cfg(feature = "spanish")]
+            7 => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    /// Get the byte standing for the language in compact encodings
+    ///
+    /// The bytes are stable, whichever languages are compiled in: they follow the declaration
+    /// order of every language, from 0 for English to 7 for Spanish. Custom languages have no
+    /// stable byte and all return 255, which [`Language::from_u8()`][Language::from_u8()]
+    /// rejects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.to_u8(), 0);
+    /// assert_eq!(Language::from_u8(0), Some(Language::English));
+    /// # #[cfg(feature = "spanish")]
+    /// assert_eq!(Language::from_u8(Language::Spanish.to_u8()), Some(Language::Spanish));
+    /// ```
+    ///
+    /// [Language::from_u8()]: ./enum.Language.html#method.from_u8
+    pub fn to_u8(&self) -> u8 {
+        match *self {
+            Language::English => 0,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => 1,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => 2,
+            #[cfg(feature = "french")]
+            Language::French => 3,
+            #[cfg(feature = "italian")]
+            Language::Italian => 4,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => 5,
+            #[cfg(feature = "korean")]
+            Language::Korean => 6,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => 7,
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(_) => 255,
+        }
+    }
+
+    /// Get the English name of the language, e.g. for a language selector
+    ///
+    /// Custom languages return the name they were registered with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.name(), "English");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Language::English => "English",
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => "Chinese (Simplified)",
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => "Chinese (Traditional)",
+            #[cfg(feature = "french")]
+            Language::French => "French",
+            #[cfg(feature = "italian")]
+            Language::Italian => "Italian",
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "Japanese",
+            #[cfg(feature = "korean")]
+            Language::Korean => "Korean",
+            #[cfg(feature = "spanish")]
+            Language::Spanish => "Spanish",
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// Get the name of the language in the language itself, e.g. "Español" for Spanish
+    ///
+    /// This is only meant for display, and custom languages return the name they were registered
+    /// with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.native_name(), "English");
+    /// ```
+    pub fn native_name(&self) -> &'static str {
+        match *self {
+            Language::English => "English",
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => "简体中文",
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => "繁體中文",
+            #[cfg(feature = "french")]
+            Language::French => "Français",
+            #[cfg(feature = "italian")]
+            Language::Italian => "Italiano",
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "日本語",
+            #[cfg(feature = "korean")]
+            Language::Korean => "한국어",
+            #[cfg(feature = "spanish")]
+            Language::Spanish => "Español",
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// Check the integrity of the word list for this language
+    ///
+    /// Returns an `ErrorKind::InvalidWordlistLength` error if there aren't exactly 2048 words,
+    /// `ErrorKind::InvalidWordlistWord` if a word is empty, contains whitespace or isn't NFKD
+    /// normalized, `ErrorKind::DuplicateWordlistWord` if a word appears more than once, and
+    /// `ErrorKind::UnsortedWordlist` if the words aren't sorted.
+    ///
+    /// Sortedness is only checked for the languages whose standard word lists are in byte order:
+    /// English, Italian, Korean and custom word lists. The French, Spanish, Japanese and Chinese
+    /// lists are sorted differently, or not at all.
+    ///
+    /// The `debug-checks` feature runs this automatically, once per language, when a word list is
+    /// first used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert!(Language::English.validate_wordlist().is_ok());
+    /// ```
+    pub fn validate_wordlist(&self) -> Result<(), ErrorKind> {
+        validate_words(&self.wordlist().inner, self.has_sorted_wordlist())
+    }
+
+    /// Whether the word list of this language is sorted in byte order
+    pub(crate) fn has_sorted_wordlist(&self) -> bool {
+        match *self {
+            Language::English => true,
+            #[cfg(feature = "italian")]
+            Language::Italian => true,
+            #[cfg(feature = "korean")]
+            Language::Korean => true,
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(_) => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// Whether the words of this language are written in the Latin script
+    pub(crate) fn is_latin_script(&self) -> bool {
+        match *self {
+            Language::English => true,
+            #[cfg(feature = "french")]
+            Language::French => true,
+            #[cfg(feature = "italian")]
+            Language::Italian => true,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
     /// Get the word list for this language
     pub fn wordlist(&self) -> &'static WordList {
         match *self {
@@ -174,9 +588,185 @@ impl Language {
             Language::Korean => &lazy::WORDLIST_KOREAN,
             #[cfg(feature = "spanish")]
             Language::Spanish => &lazy::WORDLIST_SPANISH,
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(custom) => &custom.wordlist,
         }
     }
 
+    /// Iterate over the words of this language straight from the embedded word list text,
+    /// without building the [`WordList`][WordList]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// let mut words = Language::English.words_iter();
+    ///
+    /// assert_eq!(words.next(), Some("abandon"));
+    /// assert_eq!(words.last(), Some("zoo"));
+    /// ```
+    ///
+    /// [WordList]: ./struct.WordList.html
+    pub fn words_iter(&self) -> impl Iterator<Item = &'static str> {
+        let (text, custom): (&'static str, &'static [&'static str]) = match *self {
+            Language::English => (lazy::TEXT_ENGLISH, &[]),
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => (lazy::TEXT_CHINESE_SIMPLIFIED, &[]),
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => (lazy::TEXT_CHINESE_TRADITIONAL, &[]),
+            #[cfg(feature = "french")]
+            Language::French => (lazy::TEXT_FRENCH, &[]),
+            #[cfg(feature = "italian")]
+            Language::Italian => (lazy::TEXT_ITALIAN, &[]),
+            #[cfg(feature = "japanese")]
+            Language::Japanese => (lazy::TEXT_JAPANESE, &[]),
+            #[cfg(feature = "korean")]
+            Language::Korean => (lazy::TEXT_KOREAN, &[]),
+            #[cfg(feature = "spanish")]
+            Language::Spanish => (lazy::TEXT_SPANISH, &[]),
+            // custom word lists have no embedded text, they are already in memory
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(custom) => ("", &custom.wordlist.inner),
+        };
+
+        text.split_whitespace().chain(custom.iter().cloned())
+    }
+
+    /// List the words that are in the word lists of both languages, in the order of `a`'s list
+    ///
+    /// Shared words make detecting the language of a phrase ambiguous, e.g. 100 words are both
+    /// English and French, and most words of the two Chinese lists are shared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// # #[cfg(feature = "french")]
+    /// # {
+    /// let shared = Language::shared_words(Language::English, Language::French);
+    ///
+    /// assert_eq!(shared.len(), 100);
+    /// assert_eq!(shared[0], "abandon");
+    /// # }
+    /// ```
+    pub fn shared_words(a: Language, b: Language) -> Vec<&'static str> {
+        let other = &b.wordmap().inner;
+
+        a.wordlist()
+            .inner
+            .iter()
+            .filter(|word| other.contains_key(*word))
+            .cloned()
+            .collect()
+    }
+
+    /// List every compiled-in language whose word list contains `word`, in the order of
+    /// [`Language::all()`][Language::all()]
+    ///
+    /// Meant for telling users which languages an ambiguous word belongs to while a phrase is
+    /// entered. The word must already be NFKD normalized, and custom word lists aren't searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::word_languages("zoo"), [Language::English]);
+    /// assert!(Language::word_languages("zzz").is_empty());
+    /// ```
+    ///
+    /// [Language::all()]: ./enum.Language.html#method.all
+    pub fn word_languages(word: &str) -> Vec<Language> {
+        Language::all()
+            .iter()
+            .filter(|lang| lang.wordmap().contains_word(word))
+            .cloned()
+            .collect()
+    }
+
+    /// Get the word at `index` in the word list, `None` if the index is out of range (above 2047)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.word(0), Some("abandon"));
+    /// assert_eq!(Language::English.word(2048), None);
+    /// ```
+    pub fn word(&self, index: u16) -> Option<&'static str> {
+        self.wordlist().inner.get(index as usize).cloned()
+    }
+
+    /// Get the index of `word` in the word list, `None` if the word isn't in the list
+    ///
+    /// The word must already be NFKD normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.word_index("zoo"), Some(2047));
+    /// assert_eq!(Language::English.word_index("zzz"), None);
+    /// ```
+    pub fn word_index(&self, word: &str) -> Option<u16> {
+        self.wordmap().inner.get(word).map(|&bits| bits.into())
+    }
+
+    /// Get the index of `word` in the word list, like
+    /// [`Language::word_index()`][Language::word_index()] but returning an
+    /// `ErrorKind::InvalidWord` error if the word isn't in the list
+    ///
+    /// The word must already be NFKD normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{ErrorKind, Language};
+    ///
+    /// assert_eq!(Language::English.index_of_checked("zoo").unwrap(), 2047);
+    /// assert!(matches!(Language::English.index_of_checked("zzz"), Err(ErrorKind::InvalidWord)));
+    /// ```
+    ///
+    /// [Language::word_index()]: ./enum.Language.html#method.word_index
+    pub fn index_of_checked(&self, word: &str) -> Result<u16, ErrorKind> {
+        self.word_index(word).ok_or(ErrorKind::InvalidWord)
+    }
+
+    /// Get a color for displaying `word`, as an RGB triple, `None` if the word isn't in the list
+    ///
+    /// The color only depends on the index of the word, so it is the same across sessions and
+    /// devices, and no two words of a word list share a color. Words next to each other in the
+    /// list get very different colors, and all the channels stay between 40 and 220, which keeps
+    /// the colors readable on both light and dark backgrounds.
+    ///
+    /// The word must already be NFKD normalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::Language;
+    ///
+    /// assert_eq!(Language::English.word_color("abandon"), Some([64, 112, 215]));
+    /// assert_eq!(Language::English.word_color("zzz"), None);
+    /// ```
+    pub fn word_color(&self, word: &str) -> Option<[u8; 3]> {
+        let index = u32::from(self.word_index(word)?);
+
+        // an odd multiplier permutes the 2048 indices, scattering neighbours
+        let mixed = (index * 1637 + 311) % 2048;
+
+        // 4 bits of red, 4 of green and 3 of blue
+        let red = ((mixed >> 7) & 0xf) as u8;
+        let green = ((mixed >> 3) & 0xf) as u8;
+        let blue = (mixed & 0x7) as u8;
+
+        Some([40 + red * 12, 40 + green * 12, 40 + blue * 25])
+    }
+
     /// Get a [`WordMap`][WordMap] that allows word -> index lookups in the word list
     ///
     /// The index of an individual word in the word list is used as the binary value of that word
@@ -198,21 +788,18 @@ impl Language {
             Language::Korean => &lazy::WORDMAP_KOREAN,
             #[cfg(feature = "spanish")]
             Language::Spanish => &lazy::WORDMAP_SPANISH,
+            #[cfg(feature = "dynamic-wordlists")]
+            Language::Custom(custom) => &custom.wordmap,
         }
     }
 }
 
-impl Default for Language {
-    fn default() -> Language {
-        Language::English
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::lazy;
     use super::Language;
     use super::WordList;
+    use crate::error::ErrorKind;
 
     #[test]
     fn words_by_prefix() {
@@ -235,6 +822,175 @@ mod test {
         assert!(res.is_empty());
     }
 
+    #[test]
+    fn word_by_index() {
+        assert_eq!(Language::English.word(0), Some("abandon"));
+        assert_eq!(Language::English.word(415), Some("crop"));
+        assert_eq!(Language::English.word(2047), Some("zoo"));
+        assert_eq!(Language::English.word(2048), None);
+        assert_eq!(Language::English.word(u16::MAX), None);
+    }
+
+    #[test]
+    fn words_iter() {
+        for &lang in Language::all() {
+            assert_eq!(lang.words_iter().count(), 2048);
+            assert!(lang.words_iter().eq(lang.wordlist().inner.iter().cloned()));
+        }
+    }
+
+    #[test]
+    fn word_languages() {
+        assert_eq!(Language::word_languages("actor"), [Language::English]);
+        assert!(Language::word_languages("").is_empty());
+
+        #[cfg(feature = "french")]
+        assert_eq!(
+            Language::word_languages("abandon"),
+            [Language::English, Language::French]
+        );
+        #[cfg(all(feature = "chinese-simplified", feature = "chinese-traditional"))]
+        assert_eq!(
+            Language::word_languages("的"),
+            [Language::ChineseSimplified, Language::ChineseTraditional]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "french", feature = "chinese-simplified", feature = "chinese-traditional"))]
+    fn shared_words() {
+        let shared = Language::shared_words(Language::English, Language::French);
+
+        assert_eq!(shared.len(), 100);
+        assert!(shared.contains(&"abandon"));
+        assert!(shared.contains(&"bicycle"));
+        assert_eq!(
+            Language::shared_words(Language::French, Language::English).len(),
+            shared.len()
+        );
+
+        let chinese =
+            Language::shared_words(Language::ChineseSimplified, Language::ChineseTraditional);
+        assert_eq!(chinese.len(), 1275);
+
+        assert_eq!(Language::shared_words(Language::English, Language::English).len(), 2048);
+    }
+
+    #[test]
+    fn word_index_round_trip() {
+        for &lang in Language::all() {
+            for index in 0..2048 {
+                let word = lang.word(index).unwrap();
+
+                assert_eq!(lang.word_index(word), Some(index));
+            }
+        }
+
+        assert_eq!(Language::English.word_index("woof"), None);
+    }
+
+    #[test]
+    fn word_color() {
+        let color = Language::English.word_color("crop");
+        assert_eq!(color, Language::English.word_color("crop"));
+        assert_eq!(color, Some([196, 208, 90]));
+
+        assert_ne!(
+            Language::English.word_color("abandon"),
+            Language::English.word_color("ability")
+        );
+        assert_eq!(Language::English.word_color("woof"), None);
+
+        let colors: std::collections::HashSet<[u8; 3]> = Language::English
+            .words_iter()
+            .map(|word| Language::English.word_color(word).unwrap())
+            .collect();
+        assert_eq!(colors.len(), 2048);
+        assert!(colors.iter().flatten().all(|&channel| (40..=220).contains(&channel)));
+    }
+
+    #[test]
+    fn index_of_checked() {
+        assert_eq!(Language::English.index_of_checked("abandon").unwrap(), 0);
+        assert_eq!(Language::English.index_of_checked("crop").unwrap(), 415);
+
+        assert!(matches!(
+            Language::English.index_of_checked("woof"),
+            Err(ErrorKind::InvalidWord)
+        ));
+        assert!(matches!(
+            Language::English.index_of_checked(""),
+            Err(ErrorKind::InvalidWord)
+        ));
+    }
+
+    #[test]
+    fn language_default() {
+        assert_eq!(Language::default(), Language::English);
+    }
+
+    #[test]
+    fn language_names() {
+        let mut names = Vec::new();
+
+        for lang in Language::all() {
+            assert!(!lang.name().is_empty());
+            assert!(!lang.native_name().is_empty());
+            names.push(lang.name());
+        }
+
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), Language::all().len());
+    }
+
+    #[test]
+    #[cfg(all(feature = "japanese", feature = "spanish"))]
+    fn native_language_names() {
+        assert_eq!(Language::Japanese.name(), "Japanese");
+        assert_eq!(Language::Japanese.native_name(), "日本語");
+        assert_eq!(Language::Spanish.native_name(), "Español");
+    }
+
+    #[test]
+    fn validate_builtin_wordlists() {
+        for lang in Language::all() {
+            assert!(lang.validate_wordlist().is_ok(), "{:?}", lang);
+
+            let words = &lang.wordlist().inner;
+            let sorted = words.windows(2).all(|pair| pair[0] < pair[1]);
+
+            assert_eq!(lang.has_sorted_wordlist(), sorted, "{:?}", lang);
+        }
+    }
+
+    #[test]
+    fn validate_corrupt_wordlists() {
+        let words = &lazy::WORDLIST_ENGLISH.inner;
+
+        assert!(super::validate_words(words, true).is_ok());
+
+        assert!(matches!(
+            super::validate_words(&words[1..], true),
+            Err(ErrorKind::InvalidWordlistLength(2047))
+        ));
+
+        let mut duplicate = words.clone();
+        duplicate[2047] = "abandon";
+        assert!(matches!(
+            super::validate_words(&duplicate, false),
+            Err(ErrorKind::DuplicateWordlistWord(2047))
+        ));
+
+        let mut shuffled = words.clone();
+        shuffled.swap(0, 2047);
+        assert!(super::validate_words(&shuffled, false).is_ok());
+        assert!(matches!(
+            super::validate_words(&shuffled, true),
+            Err(ErrorKind::UnsortedWordlist(1))
+        ));
+    }
+
     fn is_wordlist_nfkd(wl: &WordList) -> bool {
         for idx in 0..2047 {
             let word = wl.get_word(idx.into());
@@ -242,7 +998,12 @@ mod test {
                 return false;
             }
         }
-        return true;
+        true
+    }
+
+    #[test]
+    fn english_wordlist_is_nfkd() {
+        assert!(is_wordlist_nfkd(&lazy::WORDLIST_ENGLISH));
     }
 
     #[test]
@@ -287,6 +1048,60 @@ mod test {
         assert!(is_wordlist_nfkd(&lazy::WORDLIST_SPANISH));
     }
 
+    fn is_wordlist_sorted(wl: &WordList) -> bool {
+        let sorted = wl.inner.windows(2).all(|pair| pair[0] < pair[1]);
+        assert_eq!(wl.sorted, sorted);
+
+        sorted
+    }
+
+    #[test]
+    fn english_wordlist_is_sorted() {
+        assert!(is_wordlist_sorted(&lazy::WORDLIST_ENGLISH));
+    }
+
+    #[test]
+    #[cfg(feature = "chinese-simplified")]
+    fn chinese_simplified_wordlist_is_not_sorted() {
+        assert!(!is_wordlist_sorted(&lazy::WORDLIST_CHINESE_SIMPLIFIED));
+    }
+
+    #[test]
+    #[cfg(feature = "chinese-traditional")]
+    fn chinese_traditional_wordlist_is_not_sorted() {
+        assert!(!is_wordlist_sorted(&lazy::WORDLIST_CHINESE_TRADITIONAL));
+    }
+
+    #[test]
+    #[cfg(feature = "french")]
+    fn french_wordlist_is_not_sorted() {
+        assert!(!is_wordlist_sorted(&lazy::WORDLIST_FRENCH));
+    }
+
+    #[test]
+    #[cfg(feature = "italian")]
+    fn italian_wordlist_is_sorted() {
+        assert!(is_wordlist_sorted(&lazy::WORDLIST_ITALIAN));
+    }
+
+    #[test]
+    #[cfg(feature = "japanese")]
+    fn japanese_wordlist_is_not_sorted() {
+        assert!(!is_wordlist_sorted(&lazy::WORDLIST_JAPANESE));
+    }
+
+    #[test]
+    #[cfg(feature = "korean")]
+    fn korean_wordlist_is_sorted() {
+        assert!(is_wordlist_sorted(&lazy::WORDLIST_KOREAN));
+    }
+
+    #[test]
+    #[cfg(feature = "spanish")]
+    fn spanish_wordlist_is_not_sorted() {
+        assert!(!is_wordlist_sorted(&lazy::WORDLIST_SPANISH));
+    }
+
     #[test]
     fn from_language_code_en() {
         assert_eq!(
@@ -358,8 +1173,117 @@ mod test {
         );
     }
 
+    #[cfg(feature = "dynamic-wordlists")]
+    fn english_words() -> Vec<String> {
+        include_str!("langs/english.txt")
+            .split_whitespace()
+            .map(String::from)
+            .collect()
+    }
+
+    #[cfg(feature = "dynamic-wordlists")]
+    fn register_error(words: Vec<String>) -> ErrorKind {
+        Language::register_custom("test", words)
+            .unwrap_err()
+            .downcast::<ErrorKind>()
+            .unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "dynamic-wordlists")]
+    fn register_custom() {
+        let words = english_words().iter().map(|w| w.to_uppercase()).collect();
+        let custom = Language::register_custom("uppercase", words).unwrap();
+
+        assert_eq!(custom.word(0), Some("ABANDON"));
+        assert_eq!(custom.word_index("ZOO"), Some(2047));
+        assert_ne!(custom, Language::English);
+
+        match custom {
+            Language::Custom(inner) => assert_eq!(inner.name(), "uppercase"),
+            _ => panic!("expected a custom language"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "dynamic-wordlists")]
+    fn custom_not_serializable() {
+        let words = english_words().iter().map(|w| w.to_uppercase()).collect();
+        let custom = Language::register_custom("unserializable", words).unwrap();
+        let mnemonic = crate::Mnemonic::from_entropy(&[0; 16], custom).unwrap();
+
+        let err = serde_json::to_string(&custom).unwrap_err();
+        assert!(err.to_string().contains("cannot be serialized"));
+
+        let err = serde_json::to_string(&mnemonic).unwrap_err();
+        assert!(err.to_string().contains("cannot be serialized"));
+    }
+
+    #[test]
+    #[cfg(feature = "dynamic-wordlists")]
+    fn register_invalid_custom() {
+        let mut words = english_words();
+        words.pop();
+        assert!(matches!(
+            register_error(words),
+            ErrorKind::InvalidWordlistLength(2047)
+        ));
+
+        let mut words = english_words();
+        words[5] = "abs tract".into();
+        assert!(matches!(
+            register_error(words),
+            ErrorKind::InvalidWordlistWord(5)
+        ));
+
+        let mut words = english_words();
+        words[6] = "\u{e9}".into();
+        assert!(matches!(
+            register_error(words),
+            ErrorKind::InvalidWordlistWord(6)
+        ));
+
+        let mut words = english_words();
+        words[6] = words[5].clone();
+        assert!(matches!(
+            register_error(words),
+            ErrorKind::DuplicateWordlistWord(6)
+        ));
+
+        let mut words = english_words();
+        words.swap(10, 11);
+        assert!(matches!(
+            register_error(words),
+            ErrorKind::UnsortedWordlist(11)
+        ));
+    }
+
     #[test]
     fn from_invalid_language_code() {
         assert_eq!(Language::from_language_code("not a real language"), None);
     }
+
+    #[test]
+    fn u8_round_trip() {
+        for &lang in Language::all() {
+            assert_eq!(Language::from_u8(lang.to_u8()), Some(lang));
+        }
+
+        let compiled: Vec<u8> = Language::all().iter().map(Language::to_u8).collect();
+        for n in 0..=255 {
+            assert_eq!(Language::from_u8(n).is_some(), compiled.contains(&n));
+        }
+
+        assert_eq!(Language::from_u8(8), None);
+        assert_eq!(Language::from_u8(255), None);
+    }
+
+    #[test]
+    #[cfg(feature = "dynamic-wordlists")]
+    fn custom_u8() {
+        let custom = Language::register_custom("u8 English", english_words()).unwrap();
+
+        assert_eq!(custom.to_u8(), 255);
+        assert_eq!(Language::from_u8(custom.to_u8()), None);
+    }
 }
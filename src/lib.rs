@@ -11,14 +11,14 @@
 //! use bip39::{Mnemonic, MnemonicType, Language, Seed};
 //!
 //! /// create a new randomly generated mnemonic phrase
-//! let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+//! let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
 //!
 //! /// get the phrase
 //! let phrase: &str = mnemonic.phrase();
 //! println!("phrase: {}", phrase);
 //!
 //! /// get the HD wallet seed
-//! let seed = Seed::new(&mnemonic, "");
+//! let seed = Seed::new(&mnemonic, "").unwrap();
 //!
 //! // get the HD wallet seed as raw bytes
 //! let seed_bytes: &[u8] = seed.as_bytes();
@@ -27,23 +27,67 @@
 //! println!("{:X}", seed);
 //! ```
 //!
+//! ## Thread safety
+//!
+//! Every public type is `Send + Sync`, including with the `mlock` feature, so mnemonics and
+//! seeds can be moved or shared between the threads of a server. None of them has interior
+//! mutability: the word lists are built once on first use, behind a lock, and only read
+//! afterwards. `tests/thread_safety.rs` asserts this at compile time.
+//!
+
+// unsafe code is confined to the locked memory of the `mlock` feature
+#![cfg_attr(not(feature = "mlock"), forbid(unsafe_code))]
+#![cfg_attr(feature = "mlock", deny(unsafe_code))]
 
-#[macro_use]
 extern crate failure;
 #[macro_use]
 extern crate serde_derive;
 
+mod backup;
+#[cfg(feature = "bip85")]
+mod bip85;
+mod checksum;
+mod entropy_source;
 mod error;
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+mod fuzzy;
 mod language;
+#[cfg(feature = "mlock")]
+#[allow(unsafe_code)]
+mod locked;
 mod mnemonic;
+mod mnemonic_builder;
 mod mnemonic_type;
+#[cfg(feature = "monero")]
+pub mod monero;
+pub mod normalization;
+mod parity;
+mod report;
 mod seed;
+mod streaming;
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
 mod util;
 
 mod crypto;
+#[cfg(feature = "grin")]
+mod grin;
 
-pub use error::ErrorKind;
+pub use backup::MnemonicBackup;
+pub use checksum::Checksum;
+pub use entropy_source::{EntropySource, FixedSource};
+pub use error::{ErrorKind, HexError};
+pub use fuzzy::{fuzzy_import, FuzzyResult, FuzzyWord};
+#[cfg(feature = "grin")]
+pub use grin::GRIN_WALLET_SEED_BYTES;
 pub use language::Language;
-pub use mnemonic::Mnemonic;
-pub use mnemonic_type::MnemonicType;
-pub use seed::Seed;
+#[cfg(feature = "dynamic-wordlists")]
+pub use language::CustomLanguage;
+pub use mnemonic::{EntropyArray, Mnemonic};
+pub use mnemonic_builder::MnemonicBuilder;
+pub use mnemonic_type::{entropy_bytes, estimate_search_space, MnemonicType, SecurityLevel};
+pub use parity::ParityCheck;
+pub use report::{explain, MnemonicReport, StrengthReport, WordReport};
+pub use seed::{DerivationParams, Exposed, GenericSeed, NormalizationForm, Seed, SeedBuilder};
+pub use streaming::EntropyHandle;
@@ -0,0 +1,153 @@
+//! Locked memory for seed bytes, with the `mlock` feature
+//!
+//! Every buffer gets pages of its own, locked with `mlock(2)` so that they are never written to
+//! swap. Sharing a page would be unsafe, as unlocking one buffer would unlock its neighbours.
+//! Locking fails when the process exceeds its `RLIMIT_MEMLOCK` limit, and isn't implemented on
+//! platforms other than Unix: the buffer then lives in ordinary memory, which
+//! [`LockedBytes::is_locked()`] reports.
+use std::alloc::{self, Layout};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use zeroize::Zeroize;
+
+pub(crate) struct LockedBytes<const N: usize> {
+    ptr: NonNull<[u8; N]>,
+    locked: bool,
+}
+
+// LockedBytes owns its allocation exclusively, like a Box
+unsafe impl<const N: usize> Send for LockedBytes<N> {}
+unsafe impl<const N: usize> Sync for LockedBytes<N> {}
+
+impl<const N: usize> LockedBytes<N> {
+    /// Copy `source` into a new locked buffer, wiping `source`
+    pub(crate) fn new(source: &mut [u8; N]) -> Self {
+        let layout = Self::layout();
+
+        // SAFETY: the layout has a non-zero size
+        let ptr = unsafe { alloc::alloc_zeroed(layout) } as *mut [u8; N];
+        let ptr = match NonNull::new(ptr) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(layout),
+        };
+
+        // lock before the secret is written, so it never reaches swap
+        let locked = lock(ptr.as_ptr() as *mut u8, layout.size());
+
+        let mut bytes = LockedBytes { ptr, locked };
+        bytes.copy_from_slice(source);
+        source.zeroize();
+
+        bytes
+    }
+
+    /// Whether the pages of the buffer are locked
+    pub(crate) fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Whole pages, aligned to a page boundary
+    fn layout() -> Layout {
+        let page = page_size();
+        let size = N.max(1).div_ceil(page) * page;
+
+        Layout::from_size_align(size, page).expect("the page size is a power of two")
+    }
+}
+
+impl<const N: usize> Deref for LockedBytes<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &[u8; N] {
+        // SAFETY: the pointer is valid and initialized for as long as self lives
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<const N: usize> DerefMut for LockedBytes<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        // SAFETY: the pointer is valid and initialized, and borrowed mutably through self only
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<const N: usize> Clone for LockedBytes<N> {
+    fn clone(&self) -> Self {
+        let mut copy = **self;
+
+        LockedBytes::new(&mut copy)
+    }
+}
+
+impl<const N: usize> Drop for LockedBytes<N> {
+    fn drop(&mut self) {
+        self.deref_mut().zeroize();
+
+        let layout = Self::layout();
+        if self.locked {
+            unlock(self.ptr.as_ptr() as *mut u8, layout.size());
+        }
+
+        // SAFETY: allocated in `new()` with the same layout
+        unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+    }
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    // SAFETY: sysconf has no preconditions
+    match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as usize,
+        _ => 4096,
+    }
+}
+
+#[cfg(not(unix))]
+fn page_size() -> usize {
+    4096
+}
+
+#[cfg(unix)]
+fn lock(ptr: *mut u8, len: usize) -> bool {
+    // SAFETY: the range is a live allocation
+    unsafe { libc::mlock(ptr as *const libc::c_void, len) == 0 }
+}
+
+#[cfg(not(unix))]
+fn lock(_ptr: *mut u8, _len: usize) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn unlock(ptr: *mut u8, len: usize) {
+    // SAFETY: the range is a live allocation, locked by `lock()`
+    unsafe { libc::munlock(ptr as *const libc::c_void, len) };
+}
+
+#[cfg(not(unix))]
+fn unlock(_ptr: *mut u8, _len: usize) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn locked_bytes() {
+        let mut source = [0x42; 64];
+        let bytes = LockedBytes::new(&mut source);
+
+        assert_eq!(source, [0; 64]);
+        assert_eq!(*bytes, [0x42; 64]);
+        assert_eq!(bytes.as_ptr() as usize % page_size(), 0);
+
+        let clone = bytes.clone();
+        assert_eq!(*clone, *bytes);
+        assert_ne!(clone.as_ptr(), bytes.as_ptr());
+
+        if cfg!(unix) {
+            assert_eq!(bytes.is_locked(), clone.is_locked());
+        } else {
+            assert!(!bytes.is_locked());
+        }
+    }
+}
@@ -1,11 +1,47 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
 use std::fmt;
+use std::str::FromStr;
 use failure::Error;
 use unicode_normalization::UnicodeNormalization;
-use crate::crypto::{gen_random_bytes, sha256_first_byte};
+use rand::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+use crate::checksum::Checksum;
+use crate::crypto::{
+    gen_os_random_bytes, gen_random_bytes, gen_random_bytes_with, sha256, sha256_parts,
+};
+use crate::entropy_source::EntropySource;
 use crate::error::ErrorKind;
 use crate::language::Language;
-use crate::mnemonic_type::MnemonicType;
-use crate::util::{checksum, BitWriter, IterExt};
+use crate::mnemonic_type::{MnemonicType, SecurityLevel};
+use crate::seed::Seed;
+use crate::util::{checksum, from_hex, BitWriter, Bits11, IterExt};
+
+/// How many phrases
+/// [`Mnemonic::new_avoiding_confusables()`][Mnemonic::new_avoiding_confusables()] generates
+/// before giving up
+///
+/// [Mnemonic::new_avoiding_confusables()]: ../mnemonic/struct.Mnemonic.html#method.new_avoiding_confusables
+const CONFUSABLE_RETRIES: usize = 1000;
+
+/// The symbols of [`Mnemonic::visual_fingerprint()`][Mnemonic::visual_fingerprint()], one per
+/// nibble value
+///
+/// [Mnemonic::visual_fingerprint()]: ../mnemonic/struct.Mnemonic.html#method.visual_fingerprint
+const FINGERPRINT_SYMBOLS: [char; 16] = [
+    '🐶', '🐱', '🐭', '🐰', '🦊', '🐻', '🐼', '🐨', '🐯', '🦁', '🐮', '🐷', '🐸', '🐵', '🐔', '🐙',
+];
+
+/// Number of dice rolls needed to represent the entropy of each mnemonic type, i.e. the smallest
+/// `n` such that `6^n >= 2^ENT`
+const DICE_ROLL_COUNTS: [(MnemonicType, usize); 5] = [
+    (MnemonicType::Words12, 50),
+    (MnemonicType::Words15, 62),
+    (MnemonicType::Words18, 75),
+    (MnemonicType::Words21, 87),
+    (MnemonicType::Words24, 100),
+];
 
 /// The primary type in this crate, most tasks require creating or using one.
 ///
@@ -22,15 +58,23 @@ use crate::util::{checksum, BitWriter, IterExt};
 /// but beware that the entropy value is **not the same thing** as an HD wallet seed, and should
 /// *never* be used that way.
 ///
+/// Cloning a [`Mnemonic`][Mnemonic] performs a deep copy of the phrase and entropy, and every
+/// instance wipes its own copy from memory when dropped.
+///
+/// The `PartialEq` implementation is **not** constant-time, use [`Mnemonic::ct_eq()`][Mnemonic::ct_eq()]
+/// when comparing against a phrase entered by the user.
+///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
 /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
 /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
 /// [Mnemonic::entropy()]: ./mnemonic/struct.Mnemonic.html#method.entropy
-/// [Seed]: ./seed/struct.Seed.html
-/// [Seed::new()]: ./seed/struct.Seed.html#method.new
-/// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
+/// [Mnemonic::ct_eq()]: ./mnemonic/struct.Mnemonic.html#method.ct_eq
+/// [Seed]: ./seed/type.Seed.html
+/// [Seed::new()]: ./seed/type.Seed.html#method.new
+/// [Seed::as_bytes()]: ./seed/type.Seed.html#method.as_bytes
 ///
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "SerializedMnemonic")]
 pub struct Mnemonic {
     phrase: String,
     lang: Language,
@@ -42,12 +86,15 @@ impl Mnemonic {
     ///
     /// Use [`Mnemonic::phrase()`][Mnemonic::phrase()] to get an `str` slice of the generated phrase.
     ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the system RNG still fails after a
+    /// few retries, instead of panicking.
+    ///
     /// # Example
     ///
     /// ```
     /// use bip39::{Mnemonic, MnemonicType, Language};
     ///
-    /// let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
     /// let phrase = mnemonic.phrase();
     ///
     /// println!("phrase: {}", phrase);
@@ -57,10 +104,288 @@ impl Mnemonic {
     ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
     /// [Mnemonic::phrase()]: ./mnemonic/struct.Mnemonic.html#method.phrase
-    pub fn new(mtype: MnemonicType, lang: Language) -> Mnemonic {
-        let entropy = gen_random_bytes(mtype.entropy_bits() / 8);
+    pub fn new(mtype: MnemonicType, lang: Language) -> Result<Mnemonic, Error> {
+        let entropy = gen_random_bytes(mtype.entropy_bytes())?;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] for a named [`SecurityLevel`][SecurityLevel]
+    ///
+    /// The same as [`Mnemonic::new()`][Mnemonic::new()] with the
+    /// [`MnemonicType`][MnemonicType] of the level, for UIs offering e.g. "High (256-bit)"
+    /// rather than a number of words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, SecurityLevel};
+    ///
+    /// let mnemonic = Mnemonic::new_with_security_level(SecurityLevel::High, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().count(), 24);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::new()]: ./mnemonic/struct.Mnemonic.html#method.new
+    /// [SecurityLevel]: ./mnemonic_type/enum.SecurityLevel.html
+    /// [MnemonicType]: ./mnemonic_type/enum.MnemonicType.html
+    pub fn new_with_security_level(level: SecurityLevel, lang: Language) -> Result<Mnemonic, Error> {
+        Mnemonic::new(level.mnemonic_type(), lang)
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic], mixing user supplied bytes into the random entropy
+    ///
+    /// The entropy is the SHA-256 hash of fresh random bytes from the operating system's RNG
+    /// (`OsRng`) followed by `extra`, truncated to the size required by `mtype`. This is the usual "mix in your own randomness"
+    /// pattern (dice rolls, a hardware RNG...) for those who don't fully trust the system RNG:
+    /// the result is at least as unpredictable as the stronger of the two sources.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the system RNG can't be opened or
+    /// keeps failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let dice = b"3 1 6 6 2 4 5 1 3 3 2 6 4 1 5 2 6 3 4 4 1 2 5 6 3 1 2 4 6 5 5 3";
+    /// let mnemonic = Mnemonic::new_with_extra_entropy(dice, MnemonicType::Words24, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase().split(" ").count(), 24);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn new_with_extra_entropy(
+        extra: &[u8],
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Mnemonic, Error> {
+        let random = zeroize::Zeroizing::new(gen_os_random_bytes(mtype.entropy_bytes())?);
+
+        let mut digest = sha256_parts(&[&random, extra]);
+        let entropy = digest[..mtype.entropy_bytes()].to_vec();
+
+        digest.zeroize();
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] in which none of the `confusable_pairs` of words
+    /// appear next to each other, in either order
+    ///
+    /// Some users find phrases easier to read back or dictate when similar sounding or looking
+    /// words don't follow each other. New phrases are generated until one avoids every pair, up to
+    /// 1000 attempts, so the pairs should only rule out a small part of all the phrases. Every
+    /// rejected phrase lowers the entropy, insignificantly for a few pairs.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RetryLimitExceeded` if no phrase avoiding all the
+    /// pairs was found, or of kind `ErrorKind::RngFailure` if the system RNG keeps failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let pairs = [("bus", "buzz"), ("hair", "hire")];
+    /// let mnemonic = Mnemonic::new_avoiding_confusables(MnemonicType::Words12, Language::English, &pairs).unwrap();
+    ///
+    /// assert!(!mnemonic.phrase().contains("bus buzz"));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn new_avoiding_confusables(
+        mtype: MnemonicType,
+        lang: Language,
+        confusable_pairs: &[(&str, &str)],
+    ) -> Result<Mnemonic, Error> {
+        Mnemonic::new_avoiding_confusables_from(
+            &mut rand::thread_rng(),
+            mtype,
+            lang,
+            confusable_pairs,
+            CONFUSABLE_RETRIES,
+        )
+    }
+
+    fn new_avoiding_confusables_from<E: EntropySource>(
+        source: &mut E,
+        mtype: MnemonicType,
+        lang: Language,
+        confusable_pairs: &[(&str, &str)],
+        attempts: usize,
+    ) -> Result<Mnemonic, Error> {
+        let pairs: Vec<(String, String)> = confusable_pairs
+            .iter()
+            .map(|(a, b)| (a.trim().nfkd().collect(), b.trim().nfkd().collect()))
+            .collect();
+
+        let is_confusable = |a: &str, b: &str| {
+            pairs
+                .iter()
+                .any(|(x, y)| (x == a && y == b) || (x == b && y == a))
+        };
+
+        for _ in 0..attempts {
+            let mnemonic = Mnemonic::from_source(source, mtype, lang)?;
+            let words: Vec<&str> = mnemonic.words().collect();
+
+            if !words.windows(2).any(|pair| is_confusable(pair[0], pair[1])) {
+                return Ok(mnemonic);
+            }
+        }
+
+        Err(ErrorKind::RetryLimitExceeded(attempts))?
+    }
+
+    /// Generates a new [`Mnemonic`][Mnemonic] whose phrase starts with `word`
+    ///
+    /// The first 11 bits of the entropy are set to the index of `word`, the remaining bits are
+    /// random and the checksum is computed as usual. Meant for teaching how phrases encode
+    /// entropy: fixing a word costs 11 bits of entropy, so don't use such a phrase for funds.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidWord` if `word` isn't in the word list, or of
+    /// kind `ErrorKind::RngFailure` if the system RNG keeps failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::with_first_word("zoo", MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().next(), Some("zoo"));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn with_first_word(word: &str, mtype: MnemonicType, lang: Language) -> Result<Mnemonic, Error> {
+        let index = u16::from(lang.wordmap().get_bits(&word.trim().nfkd().collect::<String>())?);
+
+        let mut entropy = gen_random_bytes(mtype.entropy_bytes())?;
+        entropy[0] = (index >> 3) as u8;
+        entropy[1] = (entropy[1] & 0x1f) | ((index & 0x7) << 5) as u8;
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Generates new [`Mnemonic`][Mnemonic]s until the hex encoding of the [`Seed`][Seed] for
+    /// `password` starts with `prefix_hex`, giving up after `max_attempts`
+    ///
+    /// The prefix is case insensitive. Every hex digit of the prefix makes finding a match 16
+    /// times harder, and every attempt derives a seed, which is slow by design: a few digits are
+    /// practical, 8 digits (4 billion attempts on average) already aren't. Returns `None` if no
+    /// match was found, if the system RNG keeps failing, or right away if `prefix_hex` isn't
+    /// made of hex digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let (mnemonic, seed) = Mnemonic::generate_vanity_seed("a", "", MnemonicType::Words12, Language::English, 1000).unwrap();
+    ///
+    /// assert!(format!("{:x}", seed).starts_with('a'));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed]: ./seed/type.Seed.html
+    pub fn generate_vanity_seed(
+        prefix_hex: &str,
+        password: &str,
+        mtype: MnemonicType,
+        lang: Language,
+        max_attempts: usize,
+    ) -> Option<(Mnemonic, Seed)> {
+        let nibbles = prefix_hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()?;
+
+        if nibbles.len() > 128 {
+            return None;
+        }
+
+        let matches = |seed: &Seed| {
+            nibbles.iter().enumerate().all(|(i, &nibble)| {
+                let byte = seed.as_bytes()[i / 2];
+                let digit = if i % 2 == 0 { byte >> 4 } else { byte & 0xf };
+
+                digit == nibble
+            })
+        };
 
-        Mnemonic::from_entropy_unchecked(entropy, lang)
+        for _ in 0..max_attempts {
+            let mnemonic = Mnemonic::new(mtype, lang).ok()?;
+            let seed = Seed::new(&mnemonic, password).ok()?;
+
+            if matches(&seed) {
+                return Some((mnemonic, seed));
+            }
+        }
+
+        None
+    }
+
+    /// Generates `count` distinct new [`Mnemonic`][Mnemonic]s, with entropy from the operating
+    /// system's RNG (`OsRng`)
+    ///
+    /// Collisions between randomly generated mnemonics are astronomically unlikely, but this
+    /// guarantees every phrase in the returned `Vec` is unique. The order of the returned
+    /// mnemonics is arbitrary.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the system RNG can't be opened or
+    /// keeps failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonics = Mnemonic::generate_many(3, MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonics.len(), 3);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn generate_many(
+        count: usize,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Vec<Mnemonic>, Error> {
+        let mut rng = match rand::rngs::OsRng::new() {
+            Ok(rng) => rng,
+            Err(_) => Err(ErrorKind::RngFailure(1))?,
+        };
+
+        Mnemonic::generate_many_with_rng(&mut rng, count, mtype, lang)
+    }
+
+    /// Generates `count` distinct new [`Mnemonic`][Mnemonic]s using the supplied RNG
+    ///
+    /// Passing a seeded RNG gives reproducible batches, which is useful for tests and demos.
+    /// Mnemonics generated from a known seed are **not** secret and must never hold funds.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if `rng` keeps failing.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn generate_many_with_rng<R>(
+        rng: &mut R,
+        count: usize,
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<Vec<Mnemonic>, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut mnemonics = HashSet::with_capacity(count);
+
+        while mnemonics.len() < count {
+            let entropy = gen_random_bytes_with(rng, mtype.entropy_bytes())?;
+
+            mnemonics.insert(Mnemonic::from_entropy_unchecked(entropy, lang));
+        }
+
+        Ok(mnemonics.into_iter().collect())
     }
 
     /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy
@@ -85,36 +410,216 @@ impl Mnemonic {
         Ok(Self::from_entropy_unchecked(entropy, lang))
     }
 
-    fn from_entropy_unchecked<E>(entropy: E, lang: Language) -> Mnemonic
+    /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy in a fixed size array
+    ///
+    /// Unlike [`Mnemonic::from_entropy()`][Mnemonic::from_entropy()] this can't fail, as only
+    /// arrays of the valid entropy sizes (16, 20, 24, 28 or 32 bytes) are accepted at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy_array([0; 16], Language::English);
+    ///
+    /// assert_eq!("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", mnemonic.phrase());
+    /// ```
+    ///
+    /// Other sizes don't compile:
+    ///
+    /// ```compile_fail
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy_array([0; 17], Language::English);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_entropy()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy
+    pub fn from_entropy_array<const N: usize>(mut entropy: [u8; N], lang: Language) -> Mnemonic
+    where
+        [u8; N]: EntropyArray,
+    {
+        let mnemonic = Self::from_entropy_unchecked(&entropy[..], lang);
+
+        entropy.zeroize();
+
+        mnemonic
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from pre-generated entropy encoded as a hex string
+    ///
+    /// The string may optionally be prefixed with `0x` or `0X`. Lowercase, uppercase and mixed
+    /// case digits are all accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy_hex("33E46BB13A746EA41CDDE45C90846A79", Language::English).unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_entropy_hex(entropy: &str, lang: Language) -> Result<Mnemonic, Error> {
+        let entropy = from_hex(entropy)?;
+
+        Mnemonic::from_entropy(&entropy, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from entropy followed by its checksum byte, as written by
+    /// [`Mnemonic::to_entropy_with_checksum()`][Mnemonic::to_entropy_with_checksum()]
+    ///
+    /// The last byte must hold the checksum of the entropy in its most significant bits, with
+    /// the remaining bits set to zero. Otherwise an `Error` of kind `ErrorKind::InvalidChecksum`
+    /// is returned, so corrupted storage is detected on load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mut bytes = vec![0; 16];
+    /// bytes.push(0x30);
+    ///
+    /// let mnemonic = Mnemonic::from_entropy_with_checksum(&bytes, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.entropy(), &[0; 16]);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::to_entropy_with_checksum()]: ../mnemonic/struct.Mnemonic.html#method.to_entropy_with_checksum
+    pub fn from_entropy_with_checksum(bytes: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+        let (&checksum, entropy) = match bytes.split_last() {
+            Some(split) => split,
+            None => Err(ErrorKind::InvalidKeysize(0))?,
+        };
+
+        MnemonicType::for_key_size(entropy.len() * 8)?;
+
+        let width = Checksum::bit_width(entropy.len());
+
+        if checksum != Checksum::compute(entropy) << (8 - width) {
+            Err(ErrorKind::InvalidChecksum)?;
+        }
+
+        Ok(Self::from_entropy_unchecked(entropy, lang))
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from entropy written down as dice rolls by
+    /// [`Mnemonic::to_base6()`][Mnemonic::to_base6()]
+    ///
+    /// Whitespace between the rolls is ignored. Returns an `Error` of kind
+    /// `ErrorKind::InvalidDiceRoll` if a roll isn't one of `1` to `6`,
+    /// `ErrorKind::InvalidDiceRollCount` if the number of rolls doesn't match any entropy size
+    /// (50, 62, 75, 87 or 100 rolls), and `ErrorKind::DiceRollsOutOfRange` if the rolls encode a
+    /// number too large for the entropy size.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::to_base6()]: ../mnemonic/struct.Mnemonic.html#method.to_base6
+    pub fn from_base6(rolls: &str, lang: Language) -> Result<Mnemonic, Error> {
+        let mut digits = rolls
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .enumerate()
+            .map(|(index, roll)| match roll {
+                '1'..='6' => Ok(roll as u8 - b'1'),
+                _ => Err(ErrorKind::InvalidDiceRoll(index)),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        let mtype = match DICE_ROLL_COUNTS.iter().find(|(_, count)| *count == digits.len()) {
+            Some(&(mtype, _)) => mtype,
+            None => Err(ErrorKind::InvalidDiceRollCount(digits.len()))?,
+        };
+
+        // Multiply-accumulate the big endian number, one roll at a time
+        let mut entropy = vec![0u8; mtype.entropy_bytes()];
+        let mut overflow = false;
+
+        for &digit in &digits {
+            let mut carry = digit as u16;
+
+            for byte in entropy.iter_mut().rev() {
+                let value = *byte as u16 * 6 + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+
+            overflow |= carry != 0;
+        }
+
+        digits.zeroize();
+
+        if overflow {
+            entropy.zeroize();
+            Err(ErrorKind::DiceRollsOutOfRange)?;
+        }
+
+        Ok(Mnemonic::from_entropy_unchecked(entropy, lang))
+    }
+
+    pub(crate) fn from_entropy_unchecked<E>(entropy: E, lang: Language) -> Mnemonic
     where
         E: Into<Vec<u8>>,
     {
         let entropy = entropy.into();
+        let phrase = Mnemonic::entropy_to_phrase(&entropy, lang);
+
+        let mnemonic = Mnemonic {
+            phrase,
+            lang,
+            entropy,
+        };
+
+        #[cfg(feature = "debug-checks")]
+        if let Err(err) = mnemonic.assert_invariants() {
+            panic!("corrupt mnemonic: {}", err);
+        }
+
+        mnemonic
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase and its entropy, both already validated
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub(crate) fn from_parts(phrase: String, entropy: Vec<u8>, lang: Language) -> Mnemonic {
+        let mnemonic = Mnemonic {
+            phrase,
+            lang,
+            entropy,
+        };
+
+        #[cfg(feature = "debug-checks")]
+        if let Err(err) = mnemonic.assert_invariants() {
+            panic!("corrupt mnemonic: {}", err);
+        }
+
+        mnemonic
+    }
+
+    /// Encode the entropy and its checksum into the words of the phrase
+    fn entropy_to_phrase(entropy: &[u8], lang: Language) -> String {
         let wordlist = lang.wordlist();
 
-        let checksum_byte = sha256_first_byte(&entropy);
+        let checksum_byte =
+            Checksum::compute(entropy) << (8 - Checksum::bit_width(entropy.len()));
 
-        // First, create a byte iterator for the given entropy and the first byte of the
-        // hash of the entropy that will serve as the checksum (up to 8 bits for biggest
-        // entropy source).
+        // First, create a byte iterator for the given entropy and a byte holding the
+        // checksum in its most significant bits (up to 8 bits for biggest entropy source).
         //
         // Then we transform that into a bits iterator that returns 11 bits at a
         // time (as u16), which we can map to the words on the `wordlist`.
         //
         // Given the entropy is of correct size, this ought to give us the correct word
         // count.
-        let phrase = entropy
+        entropy
             .iter()
             .chain(Some(&checksum_byte))
             .bits()
             .map(|bits| wordlist.get_word(bits))
-            .join(" ");
-
-        Mnemonic {
-            phrase,
-            lang,
-            entropy,
-        }
+            .join(" ")
     }
 
     /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase
@@ -122,6 +627,11 @@ impl Mnemonic {
     /// The phrase supplied will be checked for word length and validated according to the checksum
     /// specified in BIP0039
     ///
+    /// Words may be separated by any amount of whitespace and by commas, the stored phrase always
+    /// uses single spaces. An empty or whitespace-only phrase returns an `Error` of kind
+    /// `ErrorKind::InvalidWordLength(0)`. With the `monero` feature, a 25 word Monero seed
+    /// returns `ErrorKind::LikelyMoneroSeed`.
+    ///
     /// # Example
     ///
     /// ```
@@ -135,15 +645,31 @@ impl Mnemonic {
     ///
     /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
     pub fn from_phrase(phrase: &str, lang: Language) -> Result<Mnemonic, Error> {
-        let phrase = phrase
-            .split_whitespace()
-            .map(|w| w.nfkd())
-            .join::<String>(" ");
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("from_phrase", language = ?lang).entered();
+
+        let normalized = Mnemonic::normalize_phrase(phrase);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            word_count = normalized.split(' ').count(),
+            normalization_applied = normalized != phrase,
+            "normalized phrase"
+        );
+
+        let phrase = normalized;
 
         // this also validates the checksum and phrase length before returning the entropy so we
         // can store it. We don't use the validate function here to avoid having a public API that
         // takes a phrase string and returns the entropy directly.
-        let entropy = Mnemonic::phrase_to_entropy(&phrase, lang)?;
+        let entropy = match Mnemonic::phrase_to_entropy(&phrase, lang) {
+            Ok(entropy) => entropy,
+            #[cfg(feature = "monero")]
+            Err(_) if crate::monero::is_likely_monero_seed(&phrase) => {
+                Err(ErrorKind::LikelyMoneroSeed)?
+            }
+            Err(err) => return Err(err),
+        };
 
         let mnemonic = Mnemonic {
             phrase,
@@ -151,27 +677,608 @@ impl Mnemonic {
             entropy,
         };
 
+        #[cfg(feature = "debug-checks")]
+        if let Err(err) = mnemonic.assert_invariants() {
+            panic!("corrupt mnemonic: {}", err);
+        }
+
         Ok(mnemonic)
     }
 
-    /// Validate a mnemonic phrase
+    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase, requiring it to be of
+    /// the given [`MnemonicType`][MnemonicType]
     ///
-    /// The phrase supplied will be checked for word length and validated according to the checksum
-    /// specified in BIP0039.
+    /// This enforces a policy such as "only 24 word phrases" at the import boundary. A phrase
+    /// with a different number of words returns an `Error` of kind
+    /// `ErrorKind::UnexpectedWordCount`, even if it is otherwise valid. The phrase is then
+    /// validated as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
     ///
     /// # Example
     ///
     /// ```
-    /// use bip39::{Mnemonic, Language};
+    /// use bip39::{Mnemonic, MnemonicType, Language};
     ///
-    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
     ///
-    /// assert!(Mnemonic::validate(test_mnemonic, Language::English).is_ok());
+    /// assert!(Mnemonic::from_phrase_with_type(phrase, Language::English, MnemonicType::Words12).is_ok());
+    /// assert!(Mnemonic::from_phrase_with_type(phrase, Language::English, MnemonicType::Words24).is_err());
     /// ```
-    pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
-        Mnemonic::phrase_to_entropy(phrase, lang)?;
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicType]: ../mnemonic_type/enum.MnemonicType.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_phrase_with_type(
+        phrase: &str,
+        lang: Language,
+        expected: MnemonicType,
+    ) -> Result<Mnemonic, Error> {
+        let normalized = Mnemonic::normalize_phrase(phrase);
+        let got = normalized.split_whitespace().count();
 
-        Ok(())
+        if got != expected.word_count() {
+            Err(ErrorKind::UnexpectedWordCount { expected, got })?;
+        }
+
+        Mnemonic::from_phrase(&normalized, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase read as raw bytes, e.g. from a file or a
+    /// socket
+    ///
+    /// The bytes must be valid UTF-8, otherwise an `Error` of kind `ErrorKind::InvalidUtf8` is
+    /// returned with the offset of the first invalid byte. The phrase is then validated as in
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()]. Use
+    /// [`Mnemonic::from_phrase_bytes_lossy()`][Mnemonic::from_phrase_bytes_lossy()] to decode
+    /// invalid bytes as replacement characters instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let bytes = b"park remain person kitchen mule spell knee armed position rail grid ankle\n";
+    /// let mnemonic = Mnemonic::from_phrase_bytes(bytes, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().count(), 12);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Mnemonic::from_phrase_bytes_lossy()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase_bytes_lossy
+    pub fn from_phrase_bytes(bytes: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+        let phrase = match std::str::from_utf8(bytes) {
+            Ok(phrase) => phrase,
+            Err(err) => Err(ErrorKind::InvalidUtf8(err.valid_up_to()))?,
+        };
+
+        Mnemonic::from_phrase(phrase, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a phrase read as raw bytes, replacing invalid UTF-8
+    /// with U+FFFD
+    ///
+    /// A replaced byte sequence is never part of a word in the word list, so a phrase containing
+    /// one is still rejected by [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()], but with
+    /// an error pointing at the offending word rather than a decoding error.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_phrase_bytes_lossy(bytes: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+        let phrase = zeroize::Zeroizing::new(String::from_utf8_lossy(bytes).into_owned());
+
+        Mnemonic::from_phrase(&phrase, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from a backup listing each word along with its index in
+    /// the word list
+    ///
+    /// Writing down both catches transcription errors: if the word at some position isn't the
+    /// word at its claimed index (0 to 2047) this returns an `Error` of kind
+    /// `ErrorKind::IndexWordMismatch` with the 0-based position of the pair. The words are then
+    /// validated as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mut pairs = vec![(0, "abandon"); 11];
+    /// pairs.push((3, "about"));
+    ///
+    /// let mnemonic = Mnemonic::from_indexed(&pairs, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.word_at(11), Some("about"));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_indexed(pairs: &[(usize, &str)], lang: Language) -> Result<Mnemonic, Error> {
+        let mut words = Vec::with_capacity(pairs.len());
+
+        for (position, &(index, word)) in pairs.iter().enumerate() {
+            let word = word.trim().nfkd().collect::<String>();
+
+            match lang.word_index(&word) {
+                Some(actual) if actual as usize == index => words.push(word),
+                _ => Err(ErrorKind::IndexWordMismatch { position })?,
+            }
+        }
+
+        let phrase = zeroize::Zeroizing::new(words.join(" "));
+        words.zeroize();
+
+        Mnemonic::from_phrase(&phrase, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the first letters of each word of a phrase
+    ///
+    /// Every word in the BIP39 word lists is uniquely identified by its first four letters, so
+    /// each prefix of at least four letters (or a complete word) is expanded to the matching
+    /// word before the phrase is validated as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// A prefix that matches multiple words returns an `Error` of kind
+    /// `ErrorKind::AmbiguousPrefix`, a unique prefix shorter than four letters returns
+    /// `ErrorKind::PrefixTooShort`, and a prefix that matches no word returns
+    /// `ErrorKind::InvalidWord`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let prefixes = ["park", "rema", "pers", "kitc", "mule", "spel", "knee", "arme", "posi", "rail", "grid", "ankl"];
+    /// let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English).unwrap();
+    ///
+    /// assert_eq!("park remain person kitchen mule spell knee armed position rail grid ankle", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_prefixes(prefixes: &[&str], lang: Language) -> Result<Mnemonic, Error> {
+        let wordlist = lang.wordlist();
+        let wordmap = lang.wordmap();
+
+        let mut words = Vec::with_capacity(prefixes.len());
+
+        for (position, prefix) in prefixes.iter().enumerate() {
+            let prefix = prefix.trim().nfkd().collect::<String>();
+
+            if let Ok(bits) = wordmap.get_bits(&prefix) {
+                words.push(wordlist.get_word(bits));
+                continue;
+            }
+
+            let mut candidates = wordlist.iter_words_by_prefix(&prefix);
+
+            let word = match (candidates.next(), candidates.next()) {
+                (Some(word), None) => word,
+                (Some(_), Some(_)) => Err(ErrorKind::AmbiguousPrefix(position))?,
+                (None, _) => Err(ErrorKind::InvalidWord)?,
+            };
+
+            // Count letters on the composed form, so that accents and Hangul
+            // syllables count as a single letter
+            if prefix.nfc().count() < 4 {
+                Err(ErrorKind::PrefixTooShort(position))?;
+            }
+
+            words.push(word);
+        }
+
+        Mnemonic::from_phrase(&words.join(" "), lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from the words of a phrase written down in reverse order
+    ///
+    /// Some backups store the phrase backwards, last word first. The words are put back in order
+    /// and validated as in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// This is only a convenience for reading such backups, not a security feature: reversing the
+    /// words is the first thing anyone finding the backup would try.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let words = ["vibrant", "box", "loud", "frame", "route", "inflict", "faith", "eight", "insane", "unable", "cash", "crop"];
+    /// let mnemonic = Mnemonic::from_reversed(&words, Language::English).unwrap();
+    ///
+    /// assert_eq!("crop cash unable insane eight faith inflict route frame loud box vibrant", mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn from_reversed(words: &[&str], lang: Language) -> Result<Mnemonic, Error> {
+        let ordered: Vec<&str> = words.iter().rev().copied().collect();
+        let phrase = zeroize::Zeroizing::new(ordered.join(" "));
+
+        Mnemonic::from_phrase(&phrase, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] from an existing mnemonic phrase, even if its checksum
+    /// doesn't match
+    ///
+    /// The phrase is still checked for word length and every word must be present in the word
+    /// list, but a checksum mismatch is reported through the returned `bool` (`true` if the
+    /// checksum passed) rather than as an error. This allows loading a phrase from a messy
+    /// source so that a mistyped word can be fixed afterwards.
+    ///
+    /// **Note:** a [`Mnemonic`][Mnemonic] with an invalid checksum is not a valid BIP39 mnemonic,
+    /// and deriving a [`Seed`][Seed] from it is done at your own risk. Use
+    /// [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] unless you need this behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// // the last word should be "ankle"
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid abandon";
+    /// let (mnemonic, checksum_valid) = Mnemonic::from_phrase_lenient(phrase, Language::English).unwrap();
+    ///
+    /// assert!(!checksum_valid);
+    /// assert_eq!(phrase, mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+    /// [Seed]: ../seed/type.Seed.html
+    pub fn from_phrase_lenient(phrase: &str, lang: Language) -> Result<(Mnemonic, bool), Error> {
+        let phrase = Mnemonic::normalize_phrase(phrase);
+
+        let (entropy, checksum_valid) = Mnemonic::phrase_to_entropy_unchecked(&phrase, lang)?;
+
+        let mnemonic = Mnemonic {
+            phrase,
+            lang,
+            entropy,
+        };
+
+        Ok((mnemonic, checksum_valid))
+    }
+
+    /// Recovering a [`Mnemonic`][Mnemonic] from its [`Seed`][Seed] is impossible, this always
+    /// fails
+    ///
+    /// The seed is derived with PBKDF2, a one-way function: there is no way back to the phrase
+    /// (or the password) short of guessing the phrase and deriving the seed again. This function
+    /// only exists to say so, and always returns an `Error` of kind `ErrorKind::SeedIsOneWay`.
+    /// Keep a backup of the phrase itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{ErrorKind, Language, Mnemonic, MnemonicType, Seed};
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// let err = Mnemonic::try_from_seed(&seed).unwrap_err();
+    /// assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::SeedIsOneWay));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Seed]: ../seed/type.Seed.html
+    pub fn try_from_seed(_seed: &Seed) -> Result<Infallible, Error> {
+        Err(ErrorKind::SeedIsOneWay)?
+    }
+
+    /// List every word that completes the phrase with a valid checksum
+    ///
+    /// Given all but the last word of a phrase, e.g. 11 words for a 12 word phrase, only part of
+    /// the last word is free entropy while the remaining bits are the checksum. This returns one
+    /// word for each possible value of the free bits (128 candidates for 12 words, 8 for 24
+    /// words), in word list order. Useful for users picking their words by hand, e.g. from coin
+    /// flips, who then need to choose a valid last word.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidWordLength` if the number of words given
+    /// isn't one less than a valid phrase length, and `ErrorKind::InvalidWord` if a word isn't in
+    /// the word list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let prefix = ["abandon"; 23];
+    /// let last_words = Mnemonic::valid_last_words(&prefix, Language::English).unwrap();
+    ///
+    /// assert_eq!(last_words.len(), 8);
+    /// assert!(last_words.contains(&"art"));
+    /// ```
+    pub fn valid_last_words(prefix_words: &[&str], lang: Language) -> Result<Vec<&'static str>, Error> {
+        let (mtype, prefix_bits) = Mnemonic::prefix_bits(prefix_words, lang)?;
+        let wordlist = lang.wordlist();
+
+        let free_bits = 11 - mtype.checksum_bits();
+
+        let words = (0..1u16 << free_bits)
+            .map(|free| wordlist.get_word(Mnemonic::last_word_bits(&prefix_bits, mtype, free)))
+            .collect();
+
+        Ok(words)
+    }
+
+    /// Count the words that complete the phrase with a valid checksum, for live feedback while a
+    /// phrase is being entered
+    ///
+    /// This is the length of [`Mnemonic::valid_last_words()`][Mnemonic::valid_last_words()]
+    /// when `partial_words` is one word short of a valid phrase length, e.g. 23 words. Returns
+    /// `None` if more words are missing, or if one of the words isn't in the word list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// assert_eq!(Mnemonic::remaining_completions(&["abandon"; 23], Language::English), Some(8));
+    /// assert_eq!(Mnemonic::remaining_completions(&["abandon"; 22], Language::English), None);
+    /// ```
+    ///
+    /// [Mnemonic::valid_last_words()]: ../mnemonic/struct.Mnemonic.html#method.valid_last_words
+    pub fn remaining_completions(partial_words: &[&str], lang: Language) -> Option<usize> {
+        Mnemonic::valid_last_words(partial_words, lang)
+            .ok()
+            .map(|words| words.len())
+    }
+
+    /// Compute the checksum-bearing last word for the words entered so far
+    ///
+    /// Given all but the last word of a phrase, the last word is determined once its remaining
+    /// entropy bits are fixed. This sets them to zero, so that a UI can auto-fill the last word
+    /// when building a phrase word by word. Use
+    /// [`Mnemonic::valid_last_words()`][Mnemonic::valid_last_words()] to let the user pick the
+    /// remaining bits instead.
+    ///
+    /// Returns the same errors as [`Mnemonic::valid_last_words()`][Mnemonic::valid_last_words()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let words = ["abandon"; 11];
+    /// let last_word = Mnemonic::append_checksum_word(&words, Language::English).unwrap();
+    ///
+    /// assert_eq!(last_word, "about");
+    /// ```
+    ///
+    /// [Mnemonic::valid_last_words()]: ../mnemonic/struct.Mnemonic.html#method.valid_last_words
+    pub fn append_checksum_word(words: &[&str], lang: Language) -> Result<&'static str, Error> {
+        let (mtype, prefix_bits) = Mnemonic::prefix_bits(words, lang)?;
+
+        Ok(lang.wordlist().get_word(Mnemonic::last_word_bits(&prefix_bits, mtype, 0)))
+    }
+
+    /// Check whether `last_word` is the last word of the phrase for `entropy`, without building
+    /// the phrase
+    ///
+    /// The last word holds the final entropy bits and the checksum, so exactly one word fits any
+    /// given entropy. Unlike [`Mnemonic::valid_last_words()`][Mnemonic::valid_last_words()] this
+    /// tests a single candidate, for tools that go through many entropy and word candidates,
+    /// where building a full [`Mnemonic`][Mnemonic] for each would be wasteful.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidKeysize` if the entropy doesn't have a valid
+    /// length, and `ErrorKind::InvalidWord` if `last_word` isn't in the word list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// // the phrase for all zero entropy is "abandon abandon ... abandon about"
+    /// assert!(Mnemonic::checksum_valid_for_entropy(&[0; 16], "about", Language::English).unwrap());
+    /// assert!(!Mnemonic::checksum_valid_for_entropy(&[0; 16], "abandon", Language::English).unwrap());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::valid_last_words()]: ../mnemonic/struct.Mnemonic.html#method.valid_last_words
+    pub fn checksum_valid_for_entropy(
+        entropy: &[u8],
+        last_word: &str,
+        lang: Language,
+    ) -> Result<bool, Error> {
+        let mtype = MnemonicType::for_key_size(entropy.len() * 8)?;
+        let word_bits = lang
+            .wordmap()
+            .get_bits(&last_word.trim().nfkd().collect::<String>())?;
+
+        let checksum_bits = mtype.checksum_bits();
+        let free_mask = (1u16 << (11 - checksum_bits)) - 1;
+        let free = u16::from(entropy[entropy.len() - 1]) & free_mask;
+
+        let expected = (free << checksum_bits) | u16::from(Checksum::compute(entropy));
+
+        Ok(u16::from(word_bits) == expected)
+    }
+
+    /// List every single word substitution that gives the phrase a valid checksum
+    ///
+    /// For a phrase whose checksum fails because one word was mistyped into another valid word,
+    /// this returns the `(position, replacement)` pairs that make it valid again, ordered by
+    /// position and then by word list index. One in 2^checksum bits of all the words fits at any
+    /// position, e.g. about 128 per position for 12 words, so the mistyped word still has to be
+    /// picked out, e.g. by comparing with the replacements that look alike.
+    ///
+    /// If a word isn't in the word list only substitutions at its position are returned, and none
+    /// at all for more than one such word or an invalid number of words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// // the 4th word should be "insane"
+    /// let words = ["crop", "cash", "unable", "inside", "eight", "faith", "inflict", "route", "frame", "loud", "box", "vibrant"];
+    /// let fixes = Mnemonic::single_word_fixes(&words, Language::English);
+    ///
+    /// assert!(fixes.contains(&(3, "insane")));
+    /// ```
+    pub fn single_word_fixes(words: &[&str], lang: Language) -> Vec<(usize, &'static str)> {
+        let mtype = match MnemonicType::for_word_count(words.len()) {
+            Ok(mtype) => mtype,
+            Err(_) => return Vec::new(),
+        };
+
+        let indices: Vec<Option<u16>> = words
+            .iter()
+            .map(|word| lang.word_index(&word.trim().nfkd().collect::<String>()))
+            .collect();
+
+        let positions: Vec<usize> = match indices.iter().filter(|index| index.is_none()).count() {
+            0 => (0..words.len()).collect(),
+            1 => indices.iter().position(Option::is_none).into_iter().collect(),
+            _ => return Vec::new(),
+        };
+
+        let mut fixes = Vec::new();
+
+        for position in positions {
+            for replacement in 0..2048u16 {
+                if indices[position] == Some(replacement) {
+                    continue;
+                }
+
+                let mut bits = BitWriter::with_capacity(mtype.total_bits());
+                for (i, index) in indices.iter().enumerate() {
+                    let index = if i == position { replacement } else { index.unwrap_or(0) };
+                    bits.push(Bits11::from(index));
+                }
+
+                let mut entropy = bits.into_bytes();
+                let entropy_bytes = mtype.entropy_bytes();
+                let valid = checksum(entropy[entropy_bytes], mtype.checksum_bits())
+                    == Checksum::compute(&entropy[..entropy_bytes]);
+                entropy.zeroize();
+
+                if valid {
+                    fixes.push((position, lang.word(replacement).expect("index is below 2048")));
+                }
+            }
+        }
+
+        fixes
+    }
+
+    /// Look up the bits of all but the last word of a phrase, along with the resulting type
+    fn prefix_bits(prefix_words: &[&str], lang: Language) -> Result<(MnemonicType, Vec<Bits11>), Error> {
+        let mtype = MnemonicType::for_word_count(prefix_words.len() + 1)?;
+        let wordmap = lang.wordmap();
+
+        let prefix_bits = prefix_words
+            .iter()
+            .map(|word| wordmap.get_bits(&word.trim().nfkd().collect::<String>()))
+            .collect::<Result<Vec<Bits11>, Error>>()?;
+
+        Ok((mtype, prefix_bits))
+    }
+
+    /// Compute the bits of the last word, given the bits of all the other words and the value
+    /// of the free entropy bits of the last word
+    fn last_word_bits(prefix_bits: &[Bits11], mtype: MnemonicType, free: u16) -> Bits11 {
+        let checksum_bits = mtype.checksum_bits();
+        let entropy_part = free << checksum_bits;
+
+        let mut bits = BitWriter::with_capacity(mtype.total_bits());
+        for &word_bits in prefix_bits {
+            bits.push(word_bits);
+        }
+        bits.push(Bits11::from(entropy_part));
+
+        let mut entropy = bits.into_bytes();
+        entropy.truncate(mtype.entropy_bytes());
+
+        let checksum = Checksum::compute(&entropy);
+        entropy.zeroize();
+
+        Bits11::from(entropy_part | checksum as u16)
+    }
+
+    /// Split the phrase on whitespace and commas, NFKD normalize each word and join them with
+    /// single spaces
+    ///
+    /// Invisible characters that phrases copied from web pages sometimes carry are dealt with
+    /// first: zero-width spaces (U+200B) separate words like any other whitespace, while byte order
+    /// marks (U+FEFF) and zero-width (non-)joiners (U+200C, U+200D) are removed. Commas separate
+    /// words too, so that phrases exported as `word1,word2` or `word1, word2` are accepted; no
+    /// word list contains a comma.
+    pub(crate) fn normalize_phrase(phrase: &str) -> String {
+        let cleaned: String = phrase
+            .chars()
+            .filter(|c| !matches!(c, '\u{FEFF}' | '\u{200C}' | '\u{200D}'))
+            .map(|c| if matches!(c, '\u{200B}' | ',') { ' ' } else { c })
+            .collect();
+
+        cleaned
+            .split_whitespace()
+            .map(|w| w.nfkd())
+            .join::<String>(" ")
+    }
+
+    /// Validate a mnemonic phrase
+    ///
+    /// The phrase supplied will be checked for word length and validated according to the checksum
+    /// specified in BIP0039.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// assert!(Mnemonic::validate(test_mnemonic, Language::English).is_ok());
+    /// ```
+    pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
+        Mnemonic::phrase_to_entropy(phrase, lang)?;
+
+        Ok(())
+    }
+
+    /// Validate many mnemonic phrases at once, e.g. the candidate phrases of a file
+    ///
+    /// Returns one result for each phrase, in order. Each phrase is normalized and checked exactly
+    /// like [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] does, but no
+    /// [`Mnemonic`][Mnemonic] is built and the word list is looked up once for all of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{ErrorKind, Mnemonic, Language};
+    ///
+    /// let phrases = [
+    ///     "park remain person kitchen mule spell knee armed position rail grid ankle",
+    ///     "park remain person kitchen mule spell knee armed position rail grid zoo",
+    /// ];
+    /// let results = Mnemonic::validate_many(&phrases, Language::English);
+    ///
+    /// assert!(results[0].is_ok());
+    /// assert!(matches!(results[1], Err(ErrorKind::InvalidChecksum)));
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn validate_many(phrases: &[&str], lang: Language) -> Vec<Result<(), ErrorKind>> {
+        // warm up the word map before the loop, it is built on first use
+        lang.wordmap();
+
+        phrases
+            .iter()
+            .map(|phrase| {
+                let normalized = zeroize::Zeroizing::new(Mnemonic::normalize_phrase(phrase));
+
+                match Mnemonic::phrase_to_entropy(&normalized, lang) {
+                    Ok(mut entropy) => {
+                        entropy.zeroize();
+                        Ok(())
+                    }
+                    Err(err) => Err(err
+                        .downcast::<ErrorKind>()
+                        .expect("phrase errors are all ErrorKinds")),
+                }
+            })
+            .collect()
     }
 
     /// Calculate the checksum, verify it and return the entropy
@@ -180,187 +1287,2111 @@ impl Mnemonic {
     /// used as the seed is likely to cause problems for someone eventually. All the other functions
     /// that return something like that are explicit about what it is and what to use it for.
     fn phrase_to_entropy(phrase: &str, lang: Language) -> Result<Vec<u8>, Error> {
-        let wordmap = lang.wordmap();
+        let (entropy, checksum_valid) = Mnemonic::phrase_to_entropy_unchecked(phrase, lang)?;
+
+        if !checksum_valid {
+            Err(ErrorKind::InvalidChecksum)?;
+        }
+
+        Ok(entropy)
+    }
+
+    /// Return the entropy along with whether the checksum matches, without failing on a
+    /// checksum mismatch
+    fn phrase_to_entropy_unchecked(phrase: &str, lang: Language) -> Result<(Vec<u8>, bool), Error> {
+        // an empty phrase would otherwise be reported as a single invalid (empty) word
+        if phrase.trim().is_empty() {
+            Err(ErrorKind::InvalidWordLength(0))?;
+        }
+
+        let wordmap = lang.wordmap();
+
+        // Preallocate enough space for the longest possible word list
+        let mut bits = BitWriter::with_capacity(264);
+
+        for (index, word) in phrase.split(" ").enumerate() {
+            match wordmap.get_bits(word) {
+                Ok(word_bits) => bits.push(word_bits),
+                Err(_) if lang.is_latin_script() && !word.chars().all(is_latin_char) => {
+                    Err(ErrorKind::SuspiciousCharacters {
+                        index,
+                        word: word.to_owned(),
+                    })?
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let mtype = MnemonicType::for_word_count(bits.len() / 11)?;
+
+        debug_assert!(
+            bits.len() == mtype.total_bits(),
+            "Insufficient amount of bits to validate"
+        );
+
+        let mut entropy = bits.into_bytes();
+        let entropy_bytes = mtype.entropy_bytes();
+
+        let actual_checksum = checksum(entropy[entropy_bytes], mtype.checksum_bits());
+
+        // Truncate to get rid of the byte containing the checksum
+        entropy.truncate(entropy_bytes);
+
+        let expected_checksum = Checksum::compute(&entropy);
+
+        Ok((entropy, actual_checksum == expected_checksum))
+    }
+
+    /// Get the mnemonic phrase as a string reference.
+    ///
+    /// This borrows the phrase stored inside the `Mnemonic` and never allocates. If you no longer
+    /// need the `Mnemonic`, use [`Mnemonic::into_phrase()`][Mnemonic::into_phrase()] to take
+    /// ownership of the phrase without cloning it.
+    ///
+    /// [Mnemonic::into_phrase()]: ./mnemonic/struct.Mnemonic.html#method.into_phrase
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Get the exact bytes [`Seed::new()`][Seed::new()] feeds into PBKDF2 as the password, for
+    /// comparing against the intermediate values of another wallet when seeds diverge
+    ///
+    /// These are the UTF-8 bytes of the NFKD normalized phrase, with the words separated by a
+    /// single ASCII space. NFKD turns the ideographic space (U+3000) that Japanese phrases are
+    /// usually written with into an ASCII space too, so the bytes never contain it: a wallet that
+    /// feeds U+3000 into PBKDF2 derives different seeds. The bytes are as secret as the phrase,
+    /// and are borrowed from it rather than copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.phrase_bytes(), phrase.as_bytes());
+    /// ```
+    ///
+    /// [Seed::new()]: ./seed/struct.GenericSeed.html#method.new
+    pub fn phrase_bytes(&self) -> &[u8] {
+        self.phrase.as_bytes()
+    }
+
+    /// Get an iterator over the words of the mnemonic phrase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.words().next(), Some("park"));
+    /// assert_eq!(mnemonic.words().count(), 12);
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.phrase.split(' ')
+    }
+
+    /// Get the word at the 0-based `index` of the mnemonic phrase, `None` if the phrase has fewer
+    /// words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.word_at(1), Some("remain"));
+    /// assert_eq!(mnemonic.word_at(12), None);
+    /// ```
+    pub fn word_at(&self, index: usize) -> Option<&str> {
+        self.words().nth(index)
+    }
+
+    /// List the words that appear more than once in the phrase, with their number of
+    /// occurrences, in the order they first appear
+    ///
+    /// Repeated words are perfectly valid, but many of them may hint at poor entropy, e.g. from
+    /// words picked by hand, so wallets can use this to warn the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.repeated_words(), vec![("abandon", 11)]);
+    /// ```
+    pub fn repeated_words(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+
+        for word in self.words() {
+            match counts.iter_mut().find(|(seen, _)| *seen == word) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((word, 1)),
+            }
+        }
+
+        counts.retain(|&(_, count)| count > 1);
+
+        counts
+    }
+
+    /// Render the words of the phrase numbered from 1, one per line, e.g. for a backup sheet
+    ///
+    /// This is for display only, always use [`Mnemonic::phrase()`][Mnemonic::phrase()] or
+    /// [`Seed::new()`][Seed::new()] to get the canonical phrase or derive a seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.numbered().starts_with("1. park\n2. remain\n"));
+    /// ```
+    ///
+    /// [Mnemonic::phrase()]: ../mnemonic/struct.Mnemonic.html#method.phrase
+    /// [Seed::new()]: ../seed/type.Seed.html#method.new
+    pub fn numbered(&self) -> String {
+        self.words()
+            .enumerate()
+            .map(|(i, word)| format!("{}. {}", i + 1, word))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Split the words of the phrase into rows of `per_line` words, e.g. 3 rows of 4 for a paper
+    /// backup
+    ///
+    /// The last row is shorter if the word count isn't a multiple of `per_line`. Like
+    /// [`Mnemonic::numbered()`][Mnemonic::numbered()] this is for display only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_line` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let rows = mnemonic.grouped(4);
+    ///
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows[0], ["park", "remain", "person", "kitchen"]);
+    /// ```
+    ///
+    /// [Mnemonic::numbered()]: ../mnemonic/struct.Mnemonic.html#method.numbered
+    pub fn grouped(&self, per_line: usize) -> Vec<Vec<&str>> {
+        assert!(per_line > 0, "a row needs at least one word");
+
+        let words: Vec<&str> = self.words().collect();
+
+        words.chunks(per_line).map(<[&str]>::to_vec).collect()
+    }
+
+    /// Render the phrase with every word hidden except the ones at `reveal_indices`, e.g. for a
+    /// redacted screenshot when asking for support
+    ///
+    /// Indices are 0-based, as with [`Mnemonic::words()`][Mnemonic::words()], and indices past
+    /// the end of the phrase are ignored. Hidden words are replaced with `•••••`, so the length of
+    /// the hidden words isn't revealed either.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(
+    ///     mnemonic.masked(&[0, 11]),
+    ///     "park ••••• ••••• ••••• ••••• ••••• ••••• ••••• ••••• ••••• ••••• ankle"
+    /// );
+    /// ```
+    ///
+    /// [Mnemonic::words()]: ../mnemonic/struct.Mnemonic.html#method.words
+    pub fn masked(&self, reveal_indices: &[usize]) -> String {
+        self.words()
+            .enumerate()
+            .map(|(index, word)| {
+                if reveal_indices.contains(&index) {
+                    word
+                } else {
+                    "•••••"
+                }
+            })
+            .join(" ")
+    }
+
+    /// Compare two mnemonics in constant time
+    ///
+    /// Phrases are normalized when a [`Mnemonic`][Mnemonic] is created, so this compares the
+    /// normalized phrases without exiting early on the first differing byte. Use this instead of
+    /// `==` when confirming a phrase re-typed by the user, to avoid leaking how much of it was
+    /// correct through timing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let retyped = Mnemonic::from_phrase(" park remain person kitchen mule spell knee armed position rail grid ankle", Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.ct_eq(&retyped));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn ct_eq(&self, other: &Mnemonic) -> bool {
+        let phrase_eq = self.phrase.as_bytes().ct_eq(other.phrase.as_bytes());
+
+        bool::from(phrase_eq) && self.lang == other.lang
+    }
+
+    /// Get the mnemonic type
+    pub fn mnemonic_type(&self) -> Result<MnemonicType, Error> {
+        let wordmap = self.language().wordmap();
+        let mut bits = BitWriter::with_capacity(264);
+        for word in self.phrase().split(" ") {
+            bits.push(wordmap.get_bits(word)?);
+        }
+        MnemonicType::for_word_count(bits.len() / 11)
+    }
+
+    /// Get the number of bits of security provided by the mnemonic, which is the number of
+    /// entropy bits: 128 for 12 words, 160 for 15, 192 for 18, 224 for 21 and 256 for 24 words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words18, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.security_bits(), 192);
+    /// ```
+    pub fn security_bits(&self) -> usize {
+        self.entropy.len() * 8
+    }
+
+    /// Count the bits that differ between the entropy of this and another
+    /// [`Mnemonic`][Mnemonic], `None` if their entropy isn't of the same length
+    ///
+    /// A diagnostic aid, e.g. for checking in tests that generated mnemonics look independent:
+    /// two random entropies differ in about half of their bits. The language doesn't matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let zeros = Mnemonic::from_entropy(&[0x00; 16], Language::English).unwrap();
+    /// let ones = Mnemonic::from_entropy(&[0xff; 16], Language::English).unwrap();
+    ///
+    /// assert_eq!(zeros.entropy_hamming_distance(&ones), Some(128));
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn entropy_hamming_distance(&self, other: &Mnemonic) -> Option<u32> {
+        if self.entropy.len() != other.entropy.len() {
+            return None;
+        }
+
+        let distance = self
+            .entropy
+            .iter()
+            .zip(other.entropy.iter())
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+
+        Some(distance)
+    }
+
+    /// Get the number of checksum bits at the end of the phrase: 4 for 12 words, 5 for 15, 6 for
+    /// 18, 7 for 21 and 8 for 24 words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words18, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.checksum_bits(), 6);
+    /// ```
+    pub fn checksum_bits(&self) -> usize {
+        Checksum::bit_width(self.entropy.len())
+    }
+
+    /// Split the last word of the phrase into its entropy and checksum bits
+    ///
+    /// Returns `(index, checksum, checksum_bits)`: the word list index of the last word, the
+    /// checksum, and how many of the 11 bits of the last word it takes. The last word is the only
+    /// one mixing both, its top `11 - checksum_bits` bits are the end of the entropy and the low
+    /// `checksum_bits` bits are the checksum, so `index & ((1 << checksum_bits) - 1)` equals
+    /// `checksum`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// // "about" is word 3, all of its 7 entropy bits are zero and the checksum is 0b0011
+    /// assert_eq!(mnemonic.last_word_bit_split(), (3, 0b0011, 4));
+    /// ```
+    pub fn last_word_bit_split(&self) -> (u16, u8, usize) {
+        let checksum_bits = self.checksum_bits();
+        let checksum = Checksum::compute(&self.entropy);
+
+        let free_mask = (1u16 << (11 - checksum_bits)) - 1;
+        let free = u16::from(self.entropy[self.entropy.len() - 1]) & free_mask;
+
+        ((free << checksum_bits) | u16::from(checksum), checksum, checksum_bits)
+    }
+
+    /// Re-verify the invariants every [`Mnemonic`][Mnemonic] upholds
+    ///
+    /// Checks that all the words of the phrase are in the word list, that the checksum matches
+    /// and that [`Mnemonic::entropy()`][Mnemonic::entropy()] encodes back to exactly the same
+    /// phrase. This can't fail for mnemonics built by this crate with its own word lists, it is
+    /// meant to give confidence in integrations with custom word lists. The `debug-checks`
+    /// feature runs it automatically whenever a mnemonic is generated or parsed, panicking if it
+    /// fails.
+    ///
+    /// Returns an `ErrorKind::InvalidWord`, `ErrorKind::InvalidWordLength` or
+    /// `ErrorKind::InvalidChecksum` for a broken phrase, and `ErrorKind::BrokenInvariant` if the
+    /// phrase and the entropy don't match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.assert_invariants().is_ok());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy()]: ../mnemonic/struct.Mnemonic.html#method.entropy
+    pub fn assert_invariants(&self) -> Result<(), ErrorKind> {
+        let wordmap = self.lang.wordmap();
+
+        if !self.phrase.split(' ').all(|word| wordmap.contains_word(word)) {
+            return Err(ErrorKind::InvalidWord);
+        }
+
+        let (mut entropy, checksum_valid) =
+            match Mnemonic::phrase_to_entropy_unchecked(&self.phrase, self.lang) {
+                Ok(decoded) => decoded,
+                Err(err) => {
+                    return Err(err
+                        .downcast::<ErrorKind>()
+                        .expect("phrase errors are all ErrorKinds"))
+                }
+            };
+        let entropy_matches = entropy == self.entropy;
+        entropy.zeroize();
+
+        if !checksum_valid {
+            return Err(ErrorKind::InvalidChecksum);
+        }
+
+        if !entropy_matches {
+            return Err(ErrorKind::BrokenInvariant("the phrase doesn't decode to the entropy"));
+        }
+
+        let mut phrase = Mnemonic::entropy_to_phrase(&self.entropy, self.lang);
+        let phrase_matches = phrase == self.phrase;
+        phrase.zeroize();
+
+        if !phrase_matches {
+            return Err(ErrorKind::BrokenInvariant("the entropy doesn't encode to the phrase"));
+        }
+
+        Ok(())
+    }
+
+    /// Generate a fresh random [`Mnemonic`][Mnemonic] of the same type and language as this one
+    ///
+    /// Nothing but the word count and language is kept, the new mnemonic uses new entropy as
+    /// with [`Mnemonic::new()`][Mnemonic::new()], which can fail the same way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English).unwrap();
+    /// let regenerated = mnemonic.regenerate().unwrap();
+    ///
+    /// assert_eq!(regenerated.words().count(), 15);
+    /// assert_ne!(regenerated.phrase(), mnemonic.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::new()]: ../mnemonic/struct.Mnemonic.html#method.new
+    pub fn regenerate(&self) -> Result<Mnemonic, Error> {
+        let mtype = MnemonicType::for_key_size(self.security_bits())
+            .expect("a Mnemonic always holds entropy of a valid size");
+
+        Mnemonic::new(mtype, self.lang)
+    }
+
+    /// Consume the `Mnemonic` and return the phrase as a `String`.
+    ///
+    /// This operation doesn't perform any allocations, the `String` stored inside the `Mnemonic`
+    /// is moved out as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let owned: String = mnemonic.into_phrase();
+    ///
+    /// assert_eq!(phrase, owned);
+    /// ```
+    pub fn into_phrase(mut self) -> String {
+        std::mem::take(&mut self.phrase)
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let entropy: &[u8] = mnemonic.entropy();
+    /// ```
+    ///
+    /// For a [`Mnemonic`][Mnemonic] loaded with
+    /// [`Mnemonic::from_phrase_lenient()`][Mnemonic::from_phrase_lenient()] this is the entropy
+    /// portion of the words even when the checksum doesn't match.
+    ///
+    /// **Note:** You shouldn't use the generated entropy as secrets, for that generate a new
+    /// `Seed` from the `Mnemonic`.
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase_lenient()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase_lenient
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Copy the original entropy of this [`Mnemonic`][Mnemonic] into the start of `buf`, returning
+    /// the number of bytes written.
+    ///
+    /// Unlike copying out of [`Mnemonic::entropy()`][Mnemonic::entropy()] into a `Vec<u8>` this
+    /// lets callers keep the entropy on the stack.
+    ///
+    /// Returns `ErrorKind::BufferTooSmall` if `buf` can't hold the entropy, in which case `buf`
+    /// is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let mut buf = [0u8; 32];
+    /// let len = mnemonic.entropy_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], mnemonic.entropy());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::entropy()]: ../mnemonic/struct.Mnemonic.html#method.entropy
+    pub fn entropy_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let len = self.entropy.len();
+
+        if buf.len() < len {
+            Err(ErrorKind::BufferTooSmall { needed: len, len: buf.len() })?;
+        }
+
+        buf[..len].copy_from_slice(&self.entropy);
+
+        Ok(len)
+    }
+
+    /// Get the original entropy followed by a byte holding its checksum, for storage formats
+    /// that verify their contents on load
+    ///
+    /// The checksum bits are stored left aligned in the last byte, the way they follow the
+    /// entropy in the phrase, and the unused low bits are zero. Use
+    /// [`Mnemonic::from_entropy_with_checksum()`][Mnemonic::from_entropy_with_checksum()] to
+    /// load it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+    /// let bytes = mnemonic.to_entropy_with_checksum();
+    ///
+    /// assert_eq!(bytes.len(), 17);
+    /// assert_eq!(bytes[16], 0b0011_0000);
+    /// ```
+    ///
+    /// [Mnemonic::from_entropy_with_checksum()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy_with_checksum
+    pub fn to_entropy_with_checksum(&self) -> Vec<u8> {
+        let width = Checksum::bit_width(self.entropy.len());
+
+        let mut bytes = Vec::with_capacity(self.entropy.len() + 1);
+        bytes.extend_from_slice(&self.entropy);
+        bytes.push(Checksum::compute(&self.entropy) << (8 - width));
+
+        bytes
+    }
+
+    /// Get all the bits encoded by the words of the phrase, i.e. the entropy followed by the
+    /// checksum as written in the phrase, which may be wrong for a [`Mnemonic`][Mnemonic] loaded
+    /// with [`Mnemonic::from_phrase_lenient()`][Mnemonic::from_phrase_lenient()].
+    ///
+    /// The checksum bits are stored in the most significant bits of the last byte, and the
+    /// remaining bits of that byte are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let bits = mnemonic.raw_bits();
+    ///
+    /// assert_eq!(&bits[..16], mnemonic.entropy());
+    /// assert_eq!(bits[16], 0b0011_0000);
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [Mnemonic::from_phrase_lenient()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase_lenient
+    pub fn raw_bits(&self) -> Vec<u8> {
+        let wordmap = self.lang.wordmap();
+
+        let mut bits = BitWriter::with_capacity(264);
+        for word in self.words() {
+            bits.push(
+                wordmap
+                    .get_bits(word)
+                    .expect("a Mnemonic only holds words from its word list"),
+            );
+        }
+
+        bits.into_bytes()
+    }
+
+    /// Get the entropy as a sequence of dice rolls (`1` to `6`), e.g. for a paper backup
+    ///
+    /// The rolls are the entropy written in base 6, most significant roll first, with every
+    /// digit shifted up by one to match the faces of a die. The number of rolls only depends on
+    /// the entropy size: 50 for 12 words up to 100 for 24 words. Use
+    /// [`Mnemonic::from_base6()`][Mnemonic::from_base6()] to restore the mnemonic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let rolls = mnemonic.to_base6();
+    ///
+    /// assert_eq!(rolls.len(), 50);
+    /// assert_eq!(Mnemonic::from_base6(&rolls, Language::English).unwrap().phrase(), phrase);
+    /// ```
+    ///
+    /// [Mnemonic::from_base6()]: ../mnemonic/struct.Mnemonic.html#method.from_base6
+    pub fn to_base6(&self) -> String {
+        let count = DICE_ROLL_COUNTS
+            .iter()
+            .find(|(mtype, _)| mtype.entropy_bytes() == self.entropy.len())
+            .map(|&(_, count)| count)
+            .expect("a Mnemonic always holds entropy of a valid size");
+
+        // Repeatedly divide the big endian number by 6, collecting the remainders
+        let mut number = self.entropy.clone();
+        let mut rolls = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut remainder = 0u16;
+
+            for byte in number.iter_mut() {
+                let value = (remainder << 8) | *byte as u16;
+                *byte = (value / 6) as u8;
+                remainder = value % 6;
+            }
+
+            rolls.push(b'1' + remainder as u8);
+        }
+
+        number.zeroize();
+        rolls.reverse();
+
+        String::from_utf8(rolls).expect("dice rolls are ASCII digits")
+    }
+
+    /// Render a small grid of emoji derived from the entropy, for visually comparing backups
+    ///
+    /// Showing the same 4 by 4 grid on two devices makes it likely that both hold the same
+    /// entropy, without reading out the phrase. The grid is built from a hash of the entropy, so
+    /// it doesn't reveal the entropy itself, and only depends on it: the same entropy gives the
+    /// same grid in every language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.visual_fingerprint(), "🐯🐼🐻🐱\n🦊🐼🐨🐻\n🐔🐭🐱🐸\n🐭🦊🦊🐭");
+    /// ```
+    pub fn visual_fingerprint(&self) -> String {
+        let mut input = b"bip39 visual fingerprint".to_vec();
+        input.extend_from_slice(&self.entropy);
+
+        let hash = sha256(&input);
+        input.zeroize();
+
+        hash[..8]
+            .chunks(2)
+            .map(|row| {
+                row.iter()
+                    .flat_map(|byte| vec![byte >> 4, byte & 0x0f])
+                    .map(|nibble| FINGERPRINT_SYMBOLS[nibble as usize])
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Get the original entropy value of the mnemonic phrase as a lowercase hex string.
+    ///
+    /// This is the inverse of [`Mnemonic::from_entropy_hex()`][Mnemonic::from_entropy_hex()].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert_eq!(mnemonic.entropy_hex(), "33e46bb13a746ea41cdde45c90846a79");
+    /// ```
+    ///
+    /// [Mnemonic::from_entropy_hex()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy_hex
+    pub fn entropy_hex(&self) -> String {
+        format!("{:x}", self)
+    }
+
+    /// Get the [`Language`][Language]
+    ///
+    /// [Language]: ../language/struct.Language.html
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented for the byte arrays of valid entropy sizes, see
+/// [`Mnemonic::from_entropy_array()`][Mnemonic::from_entropy_array()]
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+///
+/// [Mnemonic::from_entropy_array()]: ../mnemonic/struct.Mnemonic.html#method.from_entropy_array
+pub trait EntropyArray: sealed::Sealed {}
+
+macro_rules! impl_entropy_array {
+    ($($len:expr),*) => {
+        $(
+            impl sealed::Sealed for [u8; $len] {}
+            impl EntropyArray for [u8; $len] {}
+        )*
+    };
+}
+
+impl_entropy_array!(16, 20, 24, 28, 32);
+
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.entropy.zeroize();
+    }
+}
+
+/// The fields of a serialized [`Mnemonic`][Mnemonic], checked before a `Mnemonic` is created
+/// from them so that deserializing can't bypass validation, e.g. with an empty phrase
+///
+/// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+#[derive(Deserialize)]
+struct SerializedMnemonic {
+    phrase: String,
+    lang: Language,
+    entropy: Vec<u8>,
+}
+
+impl Drop for SerializedMnemonic {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.entropy.zeroize();
+    }
+}
+
+impl std::convert::TryFrom<SerializedMnemonic> for Mnemonic {
+    type Error = Error;
+
+    fn try_from(serialized: SerializedMnemonic) -> Result<Mnemonic, Error> {
+        // mnemonics loaded with `from_phrase_lenient()` may have a wrong checksum, but the
+        // entropy still has to match the words
+        let (mnemonic, _) = Mnemonic::from_phrase_lenient(&serialized.phrase, serialized.lang)?;
+
+        if mnemonic.entropy != serialized.entropy {
+            Err(ErrorKind::EntropyMismatch)?;
+        }
+
+        Ok(mnemonic)
+    }
+}
+
+impl AsRef<str> for Mnemonic {
+    fn as_ref(&self) -> &str {
+        self.phrase()
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.phrase(), f)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.phrase(), f)
+    }
+}
+
+impl fmt::LowerHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.entropy() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.entropy() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the character can appear in an NFKD normalized word of a Latin script word list,
+/// being either ASCII or a combining diacritical mark
+fn is_latin_char(c: char) -> bool {
+    c.is_ascii() || ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// Parse a mnemonic phrase, detecting the [`Language`][Language] from its words
+///
+/// The phrase is parsed with the first language whose word list contains every word in the
+/// phrase and whose checksum validates. If no single language contains all the words, an
+/// `Error` of kind `ErrorKind::MixedLanguages` listing every language that contains at least
+/// one of the words is returned, or `ErrorKind::InvalidWord` if none of them do.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, Language};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic: Mnemonic = phrase.parse().unwrap();
+///
+/// assert_eq!(mnemonic.language(), Language::English);
+/// ```
+///
+/// [Language]: ../language/struct.Language.html
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Mnemonic, Error> {
+        let normalized = Mnemonic::normalize_phrase(phrase);
+
+        if normalized.is_empty() {
+            Err(ErrorKind::InvalidWordLength(0))?;
+        }
+
+        let words: Vec<&str> = normalized.split(' ').collect();
+
+        let mut error = None;
+
+        for &lang in Language::all() {
+            let wordmap = lang.wordmap();
+
+            if !words.iter().all(|word| wordmap.contains_word(word)) {
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(language = ?lang, "detected language");
+
+            match Mnemonic::from_phrase(&normalized, lang) {
+                Ok(mnemonic) => return Ok(mnemonic),
+                Err(err) => error = Some(err),
+            }
+        }
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        let detected: Vec<Language> = Language::all()
+            .iter()
+            .cloned()
+            .filter(|lang| {
+                let wordmap = lang.wordmap();
+
+                words.iter().any(|word| wordmap.contains_word(word))
+            })
+            .collect();
+
+        if detected.len() > 1 {
+            Err(ErrorKind::MixedLanguages { detected })?
+        } else {
+            Err(ErrorKind::InvalidWord)?
+        }
+    }
+}
+
+impl From<Mnemonic> for String {
+    fn from(val: Mnemonic) -> String {
+        val.into_phrase()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn back_to_back() {
+        let m1 = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let m2 = Mnemonic::from_phrase(m1.phrase(), Language::English).unwrap();
+        let m3 = Mnemonic::from_entropy(m1.entropy(), Language::English).unwrap();
+
+        assert_eq!(m1.entropy(), m2.entropy(), "Entropy must be the same");
+        assert_eq!(m1.entropy(), m3.entropy(), "Entropy must be the same");
+        assert_eq!(m1.phrase(), m2.phrase(), "Phrase must be the same");
+        assert_eq!(m1.phrase(), m3.phrase(), "Phrase must be the same");
+    }
+
+    #[test]
+    fn mnemonic_new_with_security_level() {
+        let high = Mnemonic::new_with_security_level(SecurityLevel::High, Language::English).unwrap();
+        assert_eq!(high.words().count(), 24);
+        assert_eq!(high.security_bits(), 256);
+
+        let standard =
+            Mnemonic::new_with_security_level(SecurityLevel::Standard, Language::English).unwrap();
+        assert_eq!(standard.words().count(), 12);
+        assert_eq!(standard.security_bits(), 128);
+    }
+
+    #[test]
+    fn mnemonic_new_with_extra_entropy() {
+        let types = [
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ];
+
+        for &mtype in &types {
+            let m1 = Mnemonic::new_with_extra_entropy(b"extra", mtype, Language::English).unwrap();
+            let m2 = Mnemonic::new_with_extra_entropy(b"extra", mtype, Language::English).unwrap();
+
+            assert_eq!(m1.entropy().len(), mtype.entropy_bytes());
+            assert_eq!(m1.mnemonic_type().unwrap(), mtype);
+            assert_ne!(m1.entropy(), m2.entropy(), "Random part must differ");
+        }
+    }
+
+    #[test]
+    fn mnemonic_new_avoiding_confusables() {
+        let pairs = [("abandon", "abandon"), ("abandon", "about")];
+
+        for _ in 0..10 {
+            let mnemonic =
+                Mnemonic::new_avoiding_confusables(MnemonicType::Words12, Language::English, &pairs)
+                    .unwrap();
+            assert!(!mnemonic.phrase().contains("abandon about"));
+        }
+
+        // all zero entropy always gives "abandon abandon ... about"
+        let mut source = crate::FixedSource::new(&[0; 16 * 3]);
+        let err = Mnemonic::new_avoiding_confusables_from(
+            &mut source,
+            MnemonicType::Words12,
+            Language::English,
+            &[("about", "abandon")],
+            3,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::RetryLimitExceeded(3)
+        ));
+
+        let mut source = crate::FixedSource::new(&[0; 16]);
+        let mnemonic = Mnemonic::new_avoiding_confusables_from(
+            &mut source,
+            MnemonicType::Words12,
+            Language::English,
+            &[("about", "zoo")],
+            1,
+        )
+        .unwrap();
+        assert_eq!(mnemonic.entropy(), &[0; 16]);
+    }
+
+    #[test]
+    fn mnemonic_with_first_word() {
+        for &mtype in &[MnemonicType::Words12, MnemonicType::Words24] {
+            for &word in &["abandon", "crop", "letter", "zoo"] {
+                let mnemonic = Mnemonic::with_first_word(word, mtype, Language::English).unwrap();
+
+                assert_eq!(mnemonic.words().next(), Some(word));
+                assert_eq!(mnemonic.words().count(), mtype.word_count());
+                assert!(Mnemonic::validate(mnemonic.phrase(), Language::English).is_ok());
+            }
+        }
+
+        let err = Mnemonic::with_first_word("zebras", MnemonicType::Words12, Language::English);
+        assert!(matches!(
+            err.unwrap_err().downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidWord
+        ));
+    }
+
+    #[test]
+    fn mnemonic_generate_vanity_seed() {
+        let (mnemonic, seed) = Mnemonic::generate_vanity_seed(
+            "B",
+            "password",
+            MnemonicType::Words12,
+            Language::English,
+            500,
+        )
+        .unwrap();
+
+        assert!(format!("{:x}", seed).starts_with('b'));
+        assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+
+        let vanity = |prefix: &str, attempts| {
+            let mtype = MnemonicType::Words12;
+            Mnemonic::generate_vanity_seed(prefix, "", mtype, Language::English, attempts)
+        };
+
+        assert!(vanity("", 1).is_some());
+        assert!(vanity("a", 0).is_none());
+        assert!(vanity("xyz", 1000).is_none());
+        assert!(vanity(&"0".repeat(129), 1000).is_none());
+    }
+
+    #[test]
+    fn mnemonic_generate_many() {
+        let mnemonics =
+            Mnemonic::generate_many(50, MnemonicType::Words12, Language::English).unwrap();
+        let phrases: HashSet<&str> = mnemonics.iter().map(|m| m.phrase()).collect();
+
+        assert_eq!(mnemonics.len(), 50);
+        assert_eq!(phrases.len(), 50);
+        assert!(mnemonics.iter().all(|m| m.mnemonic_type().unwrap() == MnemonicType::Words12));
+    }
+
+    #[test]
+    fn mnemonic_generate_many_seeded() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let generate = || {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mnemonics = Mnemonic::generate_many_with_rng(
+                &mut rng,
+                10,
+                MnemonicType::Words24,
+                Language::English,
+            ).unwrap();
+            let mut phrases: Vec<String> = mnemonics.into_iter().map(|m| m.into_phrase()).collect();
+
+            phrases.sort();
+            phrases
+        };
+
+        assert_eq!(generate(), generate());
+        assert_eq!(generate().len(), 10);
+    }
+
+    #[test]
+    fn mnemonic_from_entropy() {
+        let entropy = &[
+            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
+            0x6A, 0x79,
+        ];
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+
+        assert_eq!(phrase, mnemonic.phrase());
+    }
+
+    #[test]
+    fn mnemonic_from_phrase() {
+        let entropy = &[
+            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
+            0x6A, 0x79,
+        ];
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(entropy, mnemonic.entropy());
+    }
+
+    #[test]
+    fn mnemonic_from_empty_phrase() {
+        for &phrase in &["", "   ", "\n\t"] {
+            let errors = vec![
+                Mnemonic::from_phrase(phrase, Language::English).unwrap_err(),
+                Mnemonic::from_phrase(&format!("{},", phrase), Language::English).unwrap_err(),
+                Mnemonic::from_phrase_lenient(phrase, Language::English).unwrap_err(),
+                Mnemonic::validate(phrase, Language::English).unwrap_err(),
+                phrase.parse::<Mnemonic>().unwrap_err(),
+            ];
+
+            for err in errors {
+                assert!(matches!(
+                    err.downcast::<ErrorKind>().unwrap(),
+                    ErrorKind::InvalidWordLength(0)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn mnemonic_from_phrase_with_commas() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+        for exported in &[
+            "park,remain,person,kitchen,mule,spell,knee,armed,position,rail,grid,ankle",
+            "park, remain, person, kitchen, mule, spell, knee, armed, position, rail, grid, ankle,",
+            "  park ,remain  person,,kitchen mule\tspell knee armed position rail grid ankle\n",
+        ] {
+            let mnemonic = Mnemonic::from_phrase(exported, Language::English).unwrap();
+            assert_eq!(mnemonic.phrase(), phrase);
+        }
+    }
+
+    #[test]
+    fn mnemonic_from_phrase_with_invisible_characters() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let pasted = "\u{FEFF}crop\u{200B}cash \u{200B}unable insane\u{200C} eight faith inflict \
+                      route fr\u{200D}ame loud box vibrant\u{200B}";
+
+        let mnemonic = Mnemonic::from_phrase(pasted, Language::English).unwrap();
+
+        assert_eq!(mnemonic.phrase(), phrase);
+        assert_eq!(pasted.parse::<Mnemonic>().unwrap().phrase(), phrase);
+    }
+
+    #[test]
+    fn mnemonic_checksum_uses_exact_bit_width() {
+        let types = [
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ];
+
+        for &mtype in &types {
+            let entropy = vec![0x5a; mtype.entropy_bytes()];
+            let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+            let words: Vec<&str> = mnemonic.words().collect();
+            let (last, prefix) = words.split_last().unwrap();
+            let last_index = Language::English.word_index(last).unwrap();
+
+            let checksum_bits = mtype.checksum_bits();
+
+            // Flip the lowest and highest checksum bits, and the lowest entropy bit of the last word
+            for &bit in &[0, checksum_bits - 1, checksum_bits] {
+                let word = Language::English.word(last_index ^ (1 << bit)).unwrap();
+                let phrase = format!("{} {}", prefix.join(" "), word);
+
+                let err = Mnemonic::from_phrase(&phrase, Language::English).unwrap_err();
+                assert!(
+                    matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidChecksum),
+                    "{:?} accepted with bit {} flipped",
+                    mtype,
+                    bit
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mnemonic_from_phrase_with_type() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+        let mnemonic =
+            Mnemonic::from_phrase_with_type(phrase, Language::English, MnemonicType::Words12)
+                .unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+
+        let err = Mnemonic::from_phrase_with_type(phrase, Language::English, MnemonicType::Words24)
+            .unwrap_err();
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::UnexpectedWordCount { expected, got } => {
+                assert_eq!(expected, MnemonicType::Words24);
+                assert_eq!(got, 12);
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn mnemonic_from_phrase_bytes() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+
+        let mnemonic = Mnemonic::from_phrase_bytes(phrase.as_bytes(), Language::English).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+
+        let mut bytes = phrase.as_bytes().to_vec();
+        bytes[5] = 0xff;
+
+        let err = Mnemonic::from_phrase_bytes(&bytes, Language::English).unwrap_err();
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::InvalidUtf8(index) => assert_eq!(index, 5),
+            other => panic!("unexpected error: {}", other),
+        }
+
+        let err = Mnemonic::from_phrase_bytes_lossy(&bytes, Language::English).unwrap_err();
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::SuspiciousCharacters { index, .. } => assert_eq!(index, 1),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn mnemonic_from_indexed() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mut pairs: Vec<(usize, &str)> = phrase
+            .split(' ')
+            .map(|word| (Language::English.word_index(word).unwrap() as usize, word))
+            .collect();
+
+        let mnemonic = Mnemonic::from_indexed(&pairs, Language::English).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+
+        // "eight" is at index 567, not 568
+        pairs[4].0 = 568;
+
+        let err = Mnemonic::from_indexed(&pairs, Language::English).unwrap_err();
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::IndexWordMismatch { position } => assert_eq!(position, 4),
+            other => panic!("unexpected error: {}", other),
+        }
+
+        pairs[4] = (4000, "eight");
+        assert!(Mnemonic::from_indexed(&pairs, Language::English).is_err());
+    }
+
+    #[test]
+    fn mnemonic_from_phrase_lenient() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let broken = "crop cash unable insane eight faith inflict route frame loud box abandon";
+
+        let (mnemonic, checksum_valid) =
+            Mnemonic::from_phrase_lenient(phrase, Language::English).unwrap();
+
+        assert!(checksum_valid);
+        assert_eq!(phrase, mnemonic.phrase());
+
+        let (mnemonic, checksum_valid) =
+            Mnemonic::from_phrase_lenient(broken, Language::English).unwrap();
+
+        assert!(!checksum_valid);
+        assert_eq!(broken, mnemonic.phrase());
+        assert!(Mnemonic::from_phrase(broken, Language::English).is_err());
+
+        let valid = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let raw_bits = mnemonic.raw_bits();
+
+        // "vibrant" and "abandon" only differ in the last 7 entropy bits and the checksum
+        assert_eq!(mnemonic.entropy()[..15], valid.entropy()[..15]);
+        assert_ne!(mnemonic.entropy(), valid.entropy());
+        assert_eq!(&raw_bits[..16], mnemonic.entropy());
+        assert_eq!(raw_bits[16], 0);
+        assert_ne!(valid.raw_bits()[16] >> 4, 0);
+        assert!(Mnemonic::from_phrase_lenient("crop cash unable", Language::English).is_err());
+    }
+
+    #[test]
+    fn mnemonic_try_from_seed() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let err = Mnemonic::try_from_seed(&seed).unwrap_err();
+
+        assert!(err.to_string().contains("one-way"));
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::SeedIsOneWay
+        ));
+    }
+
+    #[test]
+    fn mnemonic_valid_last_words() {
+        let prefix = ["abandon"; 23];
+        let last_words = Mnemonic::valid_last_words(&prefix, Language::English).unwrap();
+
+        assert_eq!(
+            last_words,
+            ["art", "diesel", "false", "kite", "organ", "ready", "surface", "trouble"]
+        );
+
+        for word in last_words {
+            let phrase = format!("{} {}", prefix.join(" "), word);
+            assert!(Mnemonic::validate(&phrase, Language::English).is_ok());
+        }
+
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let words: Vec<&str> = phrase.split(' ').collect();
+        let last_words = Mnemonic::valid_last_words(&words[..11], Language::English).unwrap();
+
+        assert_eq!(last_words.len(), 128);
+        assert!(last_words.contains(&"ankle"));
+
+        let err = Mnemonic::valid_last_words(&words, Language::English).unwrap_err();
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidWordLength(13)
+        ));
+
+        let err = Mnemonic::valid_last_words(&["parc"; 11], Language::English).unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidWord));
+    }
+
+    #[test]
+    fn mnemonic_remaining_completions() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant \
+                      crop cash unable insane eight faith inflict route frame loud box";
+        let prefix: Vec<&str> = phrase.split_whitespace().collect();
+        assert_eq!(prefix.len(), 23);
+
+        assert_eq!(Mnemonic::remaining_completions(&prefix, Language::English), Some(8));
+        assert_eq!(Mnemonic::remaining_completions(&prefix[..11], Language::English), Some(128));
+        assert_eq!(Mnemonic::remaining_completions(&prefix[..22], Language::English), None);
+        assert_eq!(Mnemonic::remaining_completions(&prefix[..10], Language::English), None);
+
+        let mut invalid = prefix.clone();
+        invalid[3] = "notaword";
+        assert_eq!(Mnemonic::remaining_completions(&invalid, Language::English), None);
+    }
+
+    #[test]
+    fn mnemonic_checksum_valid_for_entropy() {
+        let entropy = [
+            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
+            0x6A, 0x79,
+        ];
+        let lang = Language::English;
+
+        // "crop cash unable insane eight faith inflict route frame loud box vibrant"
+        assert!(Mnemonic::checksum_valid_for_entropy(&entropy, "vibrant", lang).unwrap());
+        assert!(!Mnemonic::checksum_valid_for_entropy(&entropy, "vicious", lang).unwrap());
+        assert!(Mnemonic::checksum_valid_for_entropy(&entropy[..15], "vibrant", lang).is_err());
+        assert!(Mnemonic::checksum_valid_for_entropy(&entropy, "vibranium", lang).is_err());
+
+        for &len in &[16, 20, 24, 28, 32] {
+            let mnemonic = Mnemonic::from_entropy(&vec![0xa5; len], lang).unwrap();
+            let fits = |word: &&str| {
+                Mnemonic::checksum_valid_for_entropy(mnemonic.entropy(), word, lang).unwrap()
+            };
+
+            let valid: Vec<&str> = lang.words_iter().filter(fits).collect();
+
+            assert_eq!(valid, [mnemonic.words().last().unwrap()]);
+        }
+    }
+
+    #[test]
+    fn mnemonic_single_word_fixes() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let original: Vec<&str> = phrase.split(' ').collect();
+
+        let mut words = original.clone();
+        words[3] = "inside";
+        assert!(Mnemonic::validate(&words.join(" "), Language::English).is_err());
+
+        let fixes = Mnemonic::single_word_fixes(&words, Language::English);
+        assert!(fixes.contains(&(3, "insane")));
+        assert!(!fixes.contains(&(3, "inside")));
+
+        for &(position, replacement) in &fixes {
+            let mut fixed = words.clone();
+            fixed[position] = replacement;
+            assert!(Mnemonic::validate(&fixed.join(" "), Language::English).is_ok());
+        }
+
+        // an unknown word can only be fixed in place
+        words[3] = "insaen";
+        let fixes = Mnemonic::single_word_fixes(&words, Language::English);
+        assert!(fixes.contains(&(3, "insane")));
+        assert!(fixes.iter().all(|&(position, _)| position == 3));
+
+        words[4] = "eihgt";
+        assert!(Mnemonic::single_word_fixes(&words, Language::English).is_empty());
+        assert!(Mnemonic::single_word_fixes(&original[1..], Language::English).is_empty());
+    }
+
+    #[test]
+    fn mnemonic_append_checksum_word() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let words: Vec<&str> = phrase.split(' ').collect();
+
+        for &mtype in &[MnemonicType::Words12, MnemonicType::Words15, MnemonicType::Words24] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let words: Vec<&str> = mnemonic.words().collect();
+            let prefix = &words[..words.len() - 1];
+
+            let last_word = Mnemonic::append_checksum_word(prefix, Language::English).unwrap();
+            let completed = format!("{} {}", prefix.join(" "), last_word);
+
+            assert!(Mnemonic::from_phrase(&completed, Language::English).is_ok());
+            assert_eq!(
+                last_word,
+                Mnemonic::valid_last_words(prefix, Language::English).unwrap()[0]
+            );
+        }
+
+        let err = Mnemonic::append_checksum_word(&words, Language::English).unwrap_err();
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidWordLength(13)
+        ));
+    }
+
+    #[test]
+    fn mnemonic_from_prefixes() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let prefixes = [
+            "crop", "cash", "unab", "insa", "eigh", "fait", "infl", "rout", "fram", "loud", "box",
+            "vibr",
+        ];
+
+        let mnemonic = Mnemonic::from_prefixes(&prefixes, Language::English).unwrap();
+
+        assert_eq!(phrase, mnemonic.phrase());
+    }
+
+    #[test]
+    fn mnemonic_from_reversed() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mut words: Vec<&str> = phrase.split(' ').collect();
+        words.reverse();
+
+        let mnemonic = Mnemonic::from_reversed(&words, Language::English).unwrap();
+        assert_eq!(phrase, mnemonic.phrase());
+
+        // in the original order, the checksum doesn't match
+        words.reverse();
+        let err = Mnemonic::from_reversed(&words, Language::English).unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidChecksum));
+    }
+
+    #[test]
+    fn mnemonic_from_invalid_prefixes() {
+        let mut prefixes = [
+            "crop", "cash", "unab", "insa", "eigh", "fait", "infl", "rout", "fram", "loud", "box",
+            "vibr",
+        ];
+
+        let error_kind = |prefixes: &[&str]| {
+            Mnemonic::from_prefixes(prefixes, Language::English)
+                .unwrap_err()
+                .downcast::<ErrorKind>()
+                .unwrap()
+        };
+
+        prefixes[2] = "un";
+        match error_kind(&prefixes) {
+            ErrorKind::AmbiguousPrefix(2) => {}
+            kind => panic!("unexpected error: {}", kind),
+        }
+
+        // "vib" only matches "vibrant"
+        prefixes[2] = "unab";
+        prefixes[11] = "vib";
+        match error_kind(&prefixes) {
+            ErrorKind::PrefixTooShort(11) => {}
+            kind => panic!("unexpected error: {}", kind),
+        }
+
+        prefixes[11] = "vibz";
+        match error_kind(&prefixes) {
+            ErrorKind::InvalidWord => {}
+            kind => panic!("unexpected error: {}", kind),
+        }
+    }
+
+    #[test]
+    fn mnemonic_homoglyph() {
+        // The "a" in "cash" is CYRILLIC SMALL LETTER A
+        let phrase = "crop c\u{430}sh unable insane eight faith inflict route frame loud box vibrant";
+
+        let err = Mnemonic::from_phrase(phrase, Language::English).unwrap_err();
+
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::SuspiciousCharacters { index, word } => {
+                assert_eq!(index, 1);
+                assert_eq!(word, "c\u{430}sh");
+            }
+            kind => panic!("unexpected error: {}", kind),
+        }
+
+        let err = Mnemonic::validate("crop cosh", Language::English).unwrap_err();
+
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::InvalidWord => {}
+            kind => panic!("unexpected error: {}", kind),
+        }
+    }
 
-        // Preallocate enough space for the longest possible word list
-        let mut bits = BitWriter::with_capacity(264);
+    #[test]
+    fn mnemonic_from_str() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic: Mnemonic = phrase.parse().unwrap();
+
+        assert_eq!(mnemonic.language(), Language::English);
+        assert_eq!(mnemonic.phrase(), phrase);
+        assert!("crop cash unable".parse::<Mnemonic>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "spanish")]
+    fn mnemonic_mixed_languages() {
+        let phrase = "park remain person kitchen mule spell knee armed zapato rail grid ankle";
+
+        let err = Mnemonic::from_phrase(phrase, Language::English).unwrap_err();
 
-        for word in phrase.split(" ") {
-            bits.push(wordmap.get_bits(&word)?);
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::InvalidWord => {}
+            kind => panic!("unexpected error: {}", kind),
         }
 
-        let mtype = MnemonicType::for_word_count(bits.len() / 11)?;
+        let err = phrase.parse::<Mnemonic>().unwrap_err();
 
-        debug_assert!(
-            bits.len() == mtype.total_bits(),
-            "Insufficient amount of bits to validate"
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::MixedLanguages { detected } => {
+                assert_eq!(detected, vec![Language::English, Language::Spanish]);
+            }
+            kind => panic!("unexpected error: {}", kind),
+        }
+    }
+
+    #[test]
+    fn mnemonic_serde_validates() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let json = serde_json::to_string(&mnemonic).unwrap();
+
+        assert!(serde_json::from_str::<Mnemonic>(&json).unwrap() == mnemonic);
+
+        let empty = r#"{"phrase":"","lang":"English","entropy":[]}"#;
+        let err = serde_json::from_str::<Mnemonic>(empty).unwrap_err();
+        assert!(err.to_string().starts_with("invalid number of words"), "{}", err);
+
+        let other = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let mismatched = json.replace(
+            &serde_json::to_string(mnemonic.entropy()).unwrap(),
+            &serde_json::to_string(other.entropy()).unwrap(),
         );
+        let err = serde_json::from_str::<Mnemonic>(&mismatched).unwrap_err();
+        assert!(err.to_string().starts_with(&ErrorKind::EntropyMismatch.to_string()), "{}", err);
+    }
 
-        let mut entropy = bits.into_bytes();
-        let entropy_bytes = mtype.entropy_bits() / 8;
+    #[test]
+    fn mnemonic_clone_is_deep() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let phrase = mnemonic.phrase().to_owned();
+        let entropy = mnemonic.entropy().to_vec();
 
-        let actual_checksum = checksum(entropy[entropy_bytes], mtype.checksum_bits());
+        let clone = mnemonic.clone();
 
-        // Truncate to get rid of the byte containing the checksum
-        entropy.truncate(entropy_bytes);
+        assert_ne!(mnemonic.phrase().as_ptr(), clone.phrase().as_ptr());
+        assert_ne!(mnemonic.entropy().as_ptr(), clone.entropy().as_ptr());
 
-        let checksum_byte = sha256_first_byte(&entropy);
-        let expected_checksum = checksum(checksum_byte, mtype.checksum_bits());
+        drop(mnemonic);
 
-        if actual_checksum != expected_checksum {
-            Err(ErrorKind::InvalidChecksum)?;
-        }
+        assert_eq!(clone.phrase(), phrase);
+        assert_eq!(clone.entropy(), &entropy[..]);
+    }
 
-        Ok(entropy)
+    #[test]
+    fn mnemonic_ct_eq() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let spaced = Mnemonic::from_phrase(&format!("  {}\n", phrase), Language::English).unwrap();
+        let other = Mnemonic::new(MnemonicType::Words12, Language::English).unwrap();
+        let longer = Mnemonic::new(MnemonicType::Words24, Language::English).unwrap();
+
+        assert!(mnemonic.ct_eq(&mnemonic.clone()));
+        assert!(mnemonic.ct_eq(&spaced));
+        assert!(!mnemonic.ct_eq(&other));
+        assert!(!mnemonic.ct_eq(&longer));
     }
 
-    /// Get the mnemonic phrase as a string reference.
-    pub fn phrase(&self) -> &str {
-        &self.phrase
+    #[test]
+    fn mnemonic_into_phrase() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English).unwrap();
+        let phrase = mnemonic.phrase().to_owned();
+        let ptr = mnemonic.phrase().as_ptr();
+
+        let owned = mnemonic.into_phrase();
+
+        assert_eq!(phrase, owned);
+        assert_eq!(ptr, owned.as_ptr(), "Phrase must be moved, not cloned");
     }
 
-    /// Get the mnemonic type
-    pub fn mnemonic_type(&self) -> Result<MnemonicType, Error> {
-        let wordmap = self.language().wordmap();
-        let mut bits = BitWriter::with_capacity(264);
-        for word in self.phrase().split(" ") {
-            bits.push(wordmap.get_bits(&word)?);
+    #[test]
+    fn mnemonic_entropy_hex_round_trip() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words18, Language::English).unwrap();
+
+        let hex = mnemonic.entropy_hex();
+        let prefixed = format!("{:#X}", mnemonic);
+
+        let restored = Mnemonic::from_entropy_hex(&hex, Language::English).unwrap();
+        let restored_prefixed = Mnemonic::from_entropy_hex(&prefixed, Language::English).unwrap();
+
+        assert_eq!(mnemonic.phrase(), restored.phrase());
+        assert_eq!(mnemonic.phrase(), restored_prefixed.phrase());
+
+        for invalid in &["33e46bb13a746ea41cdde45c90846a7", "+3e46bb13a746ea41cdde45c90846a79"] {
+            assert!(Mnemonic::from_entropy_hex(invalid, Language::English).is_err());
         }
-        MnemonicType::for_word_count(bits.len() / 11)
     }
 
-    /// Consume the `Mnemonic` and return the phrase as a `String`.
-    ///
-    /// This operation doesn't perform any allocations.
-    pub fn into_phrase(self) -> String {
-        self.phrase
+    #[test]
+    fn mnemonic_entropy_hex_error_source() {
+        use crate::error::HexError;
+        use std::error::Error as _;
+
+        let cases = [
+            ("33e46bb13a746ea41cdde45c90846a7", HexError::OddLength(31)),
+            (
+                "0x33e46bb13a746ea41cdde45c90846g79",
+                HexError::InvalidDigit { index: 29, digit: 'g' },
+            ),
+        ];
+
+        for (invalid, expected) in &cases {
+            let err = Mnemonic::from_entropy_hex(invalid, Language::English).unwrap_err();
+            let kind = err.downcast_ref::<ErrorKind>().unwrap();
+
+            assert!(matches!(kind, ErrorKind::InvalidHex(_)));
+
+            let source = kind.source().unwrap().downcast_ref::<HexError>();
+            assert_eq!(source, Some(expected));
+        }
     }
 
-    /// Get the original entropy value of the mnemonic phrase as a slice.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use bip39::{Mnemonic, Language};
-    ///
-    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
-    ///
-    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
-    ///
-    /// let entropy: &[u8] = mnemonic.entropy();
-    /// ```
-    ///
-    /// **Note:** You shouldn't use the generated entropy as secrets, for that generate a new
-    /// `Seed` from the `Mnemonic`.
-    pub fn entropy(&self) -> &[u8] {
-        &self.entropy
+    #[test]
+    fn mnemonic_security_bits() {
+        let expected = [
+            (MnemonicType::Words12, 128),
+            (MnemonicType::Words15, 160),
+            (MnemonicType::Words18, 192),
+            (MnemonicType::Words21, 224),
+            (MnemonicType::Words24, 256),
+        ];
+
+        for &(mtype, bits) in &expected {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let restored = Mnemonic::from_phrase(mnemonic.phrase(), Language::English).unwrap();
+
+            assert_eq!(mnemonic.security_bits(), bits);
+            assert_eq!(restored.security_bits(), bits);
+        }
     }
 
-    /// Get the [`Language`][Language]
-    ///
-    /// [Language]: ../language/struct.Language.html
-    pub fn language(&self) -> Language {
-        self.lang
+    #[test]
+    fn mnemonic_numbered() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let numbered = mnemonic.numbered();
+        let lines: Vec<&str> = numbered.lines().collect();
+
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], "1. crop");
+        assert_eq!(lines[9], "10. loud");
+        assert_eq!(lines[11], "12. vibrant");
+        assert!(!numbered.ends_with('\n'));
     }
-}
 
-impl AsRef<str> for Mnemonic {
-    fn as_ref(&self) -> &str {
-        self.phrase()
+    #[test]
+    fn mnemonic_grouped() {
+        let mnemonic = Mnemonic::from_entropy(&[0x7f; 32], Language::English).unwrap();
+        let words: Vec<&str> = mnemonic.words().collect();
+
+        let rows = mnemonic.grouped(4);
+        assert_eq!(rows.len(), 6);
+        assert!(rows.iter().all(|row| row.len() == 4));
+        assert_eq!(rows.concat(), words);
+
+        let rows = mnemonic.grouped(5);
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[4].len(), 4);
+        assert_eq!(rows.concat(), words);
+
+        assert_eq!(mnemonic.grouped(24).len(), 1);
+        assert_eq!(mnemonic.grouped(100), vec![words]);
     }
-}
 
-impl fmt::Display for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(self.phrase(), f)
+    #[test]
+    fn mnemonic_from_entropy_array() {
+        let entropy = [0x7f; 16];
+        let mnemonic = Mnemonic::from_entropy_array(entropy, Language::English);
+
+        assert_eq!(
+            mnemonic.phrase(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+        assert_eq!(mnemonic.entropy(), entropy);
+
+        let entropy = [0x80; 32];
+        let mnemonic = Mnemonic::from_entropy_array(entropy, Language::English);
+
+        assert_eq!(
+            mnemonic.phrase(),
+            Mnemonic::from_entropy(&entropy, Language::English).unwrap().phrase()
+        );
+        assert_eq!(mnemonic.entropy(), entropy);
     }
-}
 
-impl fmt::Debug for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self.phrase(), f)
+    #[test]
+    fn mnemonic_regenerate() {
+        for &mtype in &[MnemonicType::Words12, MnemonicType::Words21, MnemonicType::Words24] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let regenerated = mnemonic.regenerate().unwrap();
+
+            assert_eq!(regenerated.mnemonic_type().unwrap(), mtype);
+            assert_eq!(regenerated.language(), Language::English);
+            assert_ne!(regenerated.phrase(), mnemonic.phrase());
+            assert_ne!(regenerated.entropy(), mnemonic.entropy());
+        }
     }
-}
 
-impl fmt::LowerHex for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            f.write_str("0x")?;
+    #[test]
+    fn mnemonic_base6_round_trip() {
+        for &(mtype, count) in &DICE_ROLL_COUNTS {
+            for _ in 0..8 {
+                let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+                let rolls = mnemonic.to_base6();
+
+                assert_eq!(rolls.len(), count);
+                assert!(rolls.chars().all(|roll| ('1'..='6').contains(&roll)));
+
+                let restored = Mnemonic::from_base6(&rolls, Language::English).unwrap();
+                assert_eq!(restored.phrase(), mnemonic.phrase());
+            }
+
+            // The extremes of the entropy range
+            for &byte in &[0x00, 0xff] {
+                let entropy = vec![byte; mtype.entropy_bytes()];
+                let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+                let restored = Mnemonic::from_base6(&mnemonic.to_base6(), Language::English);
+                assert_eq!(restored.unwrap().entropy(), &entropy[..]);
+            }
         }
 
-        for byte in self.entropy() {
-            write!(f, "{:02x}", byte)?;
+        assert_eq!(
+            Mnemonic::from_entropy(&[0; 16], Language::English).unwrap().to_base6(),
+            "1".repeat(50)
+        );
+    }
+
+    #[test]
+    fn mnemonic_from_invalid_base6() {
+        let error = |rolls: &str| {
+            Mnemonic::from_base6(rolls, Language::English)
+                .unwrap_err()
+                .downcast::<ErrorKind>()
+                .unwrap()
+        };
+
+        let rolls = format!("1 2 3 0 {}", "1".repeat(46));
+        assert!(matches!(error(&rolls), ErrorKind::InvalidDiceRoll(3)));
+
+        assert!(matches!(error(&"1".repeat(49)), ErrorKind::InvalidDiceRollCount(49)));
+
+        // 6^50 - 1 is larger than 2^128 - 1
+        assert!(matches!(error(&"6".repeat(50)), ErrorKind::DiceRollsOutOfRange));
+    }
+
+    #[test]
+    fn mnemonic_masked() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let masked = mnemonic.masked(&[1, 9, 42]);
+        let words: Vec<&str> = masked.split(' ').collect();
+
+        assert_eq!(words.len(), 12);
+
+        for (index, (masked, word)) in words.iter().zip(mnemonic.words()).enumerate() {
+            if index == 1 || index == 9 {
+                assert_eq!(masked, &word);
+            } else {
+                assert_eq!(masked, &"•••••");
+            }
         }
 
-        Ok(())
+        assert!(mnemonic.masked(&[]).split(' ').all(|word| word == "•••••"));
     }
-}
 
-impl fmt::UpperHex for Mnemonic {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            f.write_str("0x")?;
+    #[test]
+    fn mnemonic_entropy_into() {
+        let entropy = [0x5a; 20];
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+        let mut exact = [0u8; 20];
+        assert_eq!(mnemonic.entropy_into(&mut exact).unwrap(), 20);
+        assert_eq!(exact, entropy);
+
+        let mut oversized = [0xffu8; 32];
+        assert_eq!(mnemonic.entropy_into(&mut oversized).unwrap(), 20);
+        assert_eq!(&oversized[..20], &entropy[..]);
+        assert!(oversized[20..].iter().all(|&byte| byte == 0xff));
+
+        let mut undersized = [0u8; 16];
+        let err = mnemonic.entropy_into(&mut undersized).unwrap_err();
+        match err.downcast::<ErrorKind>().unwrap() {
+            ErrorKind::BufferTooSmall { needed, len } => assert_eq!((needed, len), (20, 16)),
+            other => panic!("unexpected error: {}", other),
         }
+        assert_eq!(undersized, [0u8; 16]);
+    }
 
-        for byte in self.entropy() {
-            write!(f, "{:02X}", byte)?;
+    #[test]
+    fn mnemonic_word_at() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        for (index, word) in phrase.split(' ').enumerate() {
+            assert_eq!(mnemonic.word_at(index), Some(word));
         }
 
-        Ok(())
+        assert_eq!(mnemonic.word_at(12), None);
+        assert_eq!(mnemonic.word_at(usize::MAX), None);
     }
-}
 
-impl From<Mnemonic> for String {
-    fn from(val: Mnemonic) -> String {
-        val.into_phrase()
+    #[test]
+    fn mnemonic_visual_fingerprint() {
+        let entropy = [0x5a; 16];
+        let english = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+        let fingerprint = english.visual_fingerprint();
+
+        assert_eq!(fingerprint, english.visual_fingerprint());
+        assert_eq!(fingerprint, english.clone().visual_fingerprint());
+
+        #[cfg(feature = "korean")]
+        {
+            let korean = Mnemonic::from_entropy(&entropy, Language::Korean).unwrap();
+            assert_eq!(fingerprint, korean.visual_fingerprint());
+        }
+
+        let rows: Vec<&str> = fingerprint.split('\n').collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.chars().count() == 4));
+
+        let other = Mnemonic::from_entropy(&[0x5b; 16], Language::English).unwrap();
+        assert_ne!(fingerprint, other.visual_fingerprint());
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn mnemonic_entropy_with_checksum() {
+        for &mtype in &[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let bytes = mnemonic.to_entropy_with_checksum();
+
+            assert_eq!(bytes.len(), mtype.entropy_bytes() + 1);
+            assert_eq!(&bytes[..mtype.entropy_bytes()], mnemonic.entropy());
+
+            // the checksum byte holds the same bits that end the phrase
+            let raw_bits = mnemonic.raw_bits();
+            assert_eq!(bytes[mtype.entropy_bytes()], raw_bits[mtype.entropy_bytes()]);
+
+            let restored = Mnemonic::from_entropy_with_checksum(&bytes, Language::English).unwrap();
+            assert_eq!(restored.phrase(), mnemonic.phrase());
+        }
+    }
 
     #[test]
-    fn back_to_back() {
-        let m1 = Mnemonic::new(MnemonicType::Words12, Language::English);
-        let m2 = Mnemonic::from_phrase(m1.phrase(), Language::English).unwrap();
-        let m3 = Mnemonic::from_entropy(m1.entropy(), Language::English).unwrap();
+    fn mnemonic_entropy_with_tampered_checksum() {
+        let mnemonic = Mnemonic::from_entropy(&[0x5a; 32], Language::English).unwrap();
+        let bytes = mnemonic.to_entropy_with_checksum();
 
-        assert_eq!(m1.entropy(), m2.entropy(), "Entropy must be the same");
-        assert_eq!(m1.entropy(), m3.entropy(), "Entropy must be the same");
-        assert_eq!(m1.phrase(), m2.phrase(), "Phrase must be the same");
-        assert_eq!(m1.phrase(), m3.phrase(), "Phrase must be the same");
+        let mut tampered_checksum = bytes.clone();
+        tampered_checksum[32] ^= 0x80;
+
+        let mut tampered_entropy = bytes.clone();
+        tampered_entropy[0] ^= 0x01;
+
+        let short = Mnemonic::from_entropy(&[0x5a; 16], Language::English).unwrap();
+        let mut padding_bits = short.to_entropy_with_checksum();
+        padding_bits[16] |= 0x01;
+
+        for tampered in &[tampered_checksum, tampered_entropy, padding_bits] {
+            let err = Mnemonic::from_entropy_with_checksum(tampered, Language::English).unwrap_err();
+
+            match err.downcast::<ErrorKind>().unwrap() {
+                ErrorKind::InvalidChecksum => {}
+                other => panic!("unexpected error: {}", other),
+            }
+        }
+
+        assert!(Mnemonic::from_entropy_with_checksum(&[], Language::English).is_err());
+        assert!(Mnemonic::from_entropy_with_checksum(&bytes[1..], Language::English).is_err());
     }
 
     #[test]
-    fn mnemonic_from_entropy() {
-        let entropy = &[
-            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
-            0x6A, 0x79,
-        ];
+    fn mnemonic_repeated_words() {
         let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert!(mnemonic.repeated_words().is_empty());
 
-        let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+        let phrase = "cash crop cash box crop cash unable box loud frame route accident";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
 
-        assert_eq!(phrase, mnemonic.phrase());
+        let expected = vec![("cash", 3), ("crop", 2), ("box", 2)];
+
+        assert_eq!(mnemonic.repeated_words(), expected);
     }
 
     #[test]
-    fn mnemonic_from_phrase() {
-        let entropy = &[
-            0x33, 0xE4, 0x6B, 0xB1, 0x3A, 0x74, 0x6E, 0xA4, 0x1C, 0xDD, 0xE4, 0x5C, 0x90, 0x84,
-            0x6A, 0x79,
+    fn mnemonic_checksum_bits() {
+        for &(mtype, bits) in &[
+            (MnemonicType::Words12, 4),
+            (MnemonicType::Words15, 5),
+            (MnemonicType::Words18, 6),
+            (MnemonicType::Words21, 7),
+            (MnemonicType::Words24, 8),
+        ] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+
+            assert_eq!(mnemonic.checksum_bits(), bits);
+            assert_eq!(mnemonic.checksum_bits(), mtype.checksum_bits() as usize);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "japanese")]
+    fn mnemonic_phrase_bytes() {
+        // from the Japanese test vectors of BIP39, written with ideographic spaces
+        let words = [
+            "あいこくしん", "あいこくしん", "あいこくしん", "あいこくしん", "あいこくしん", "あいこくしん",
+            "あいこくしん", "あいこくしん", "あいこくしん", "あいこくしん", "あいこくしん", "あおぞら",
         ];
-        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let phrase = words.join("\u{3000}");
+        let mnemonic = Mnemonic::from_phrase(&phrase, Language::Japanese).unwrap();
+
+        let bytes = mnemonic.phrase_bytes();
+        let expected: String = words.join(" ").nfkd().collect();
 
+        assert_eq!(bytes, expected.as_bytes());
+        assert_eq!(bytes.iter().filter(|&&b| b == b' ').count(), 11);
+        assert!(!bytes.windows(3).any(|window| window == "\u{3000}".as_bytes()));
+        assert_eq!(
+            crate::crypto::pbkdf2(bytes, "mnemonic").unwrap(),
+            Seed::new(&mnemonic, "").unwrap().as_bytes()
+        );
+    }
+
+    #[test]
+    fn mnemonic_assert_invariants() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert!(mnemonic.assert_invariants().is_ok());
 
-        assert_eq!(entropy, mnemonic.entropy());
+        let tampered = |phrase: &str, entropy: &[u8]| Mnemonic {
+            phrase: phrase.to_owned(),
+            lang: Language::English,
+            entropy: entropy.to_vec(),
+        };
+
+        let swapped = "remain park person kitchen mule spell knee armed position rail grid ankle";
+        assert!(matches!(
+            tampered(swapped, mnemonic.entropy()).assert_invariants(),
+            Err(ErrorKind::InvalidChecksum)
+        ));
+
+        let unknown = "park remain person kitchen mule spell knee armed position rail grid ankles";
+        assert!(matches!(
+            tampered(unknown, mnemonic.entropy()).assert_invariants(),
+            Err(ErrorKind::InvalidWord)
+        ));
+
+        let double_space =
+            "park  remain person kitchen mule spell knee armed position rail grid ankle";
+        assert!(matches!(
+            tampered(double_space, mnemonic.entropy()).assert_invariants(),
+            Err(ErrorKind::InvalidWord)
+        ));
+
+        let short = "park remain person kitchen mule spell knee armed position rail grid";
+        assert!(matches!(
+            tampered(short, mnemonic.entropy()).assert_invariants(),
+            Err(ErrorKind::InvalidWordLength(11))
+        ));
+
+        let other_entropy = tampered(phrase, &[0; 16]);
+        assert!(matches!(
+            other_entropy.assert_invariants(),
+            Err(ErrorKind::BrokenInvariant(_))
+        ));
+    }
+
+    #[test]
+    fn mnemonic_validate_many() {
+        let phrases = [
+            "park remain person kitchen mule spell knee armed position rail grid ankle",
+            "Park, remain person kitchen mule spell knee armed position rail grid ankle",
+            "park remain person kitchen mule spell knee armed position rail grid zoo",
+            "park remain person kitchen mule spell knee armed position rail grid",
+            "park remain person kitchen mule spell knee armed position rail grid ankles",
+            "",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ];
+
+        let results = Mnemonic::validate_many(&phrases, Language::English);
+
+        assert_eq!(results.len(), phrases.len());
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(ErrorKind::InvalidWord)));
+        assert!(matches!(results[2], Err(ErrorKind::InvalidChecksum)));
+        assert!(matches!(results[3], Err(ErrorKind::InvalidWordLength(11))));
+        assert!(matches!(results[4], Err(ErrorKind::InvalidWord)));
+        assert!(matches!(results[5], Err(ErrorKind::InvalidWordLength(0))));
+        assert!(results[6].is_ok());
+
+        assert!(Mnemonic::validate_many(&[], Language::English).is_empty());
+    }
+
+    #[test]
+    fn mnemonic_last_word_bit_split() {
+        let index = |word| Language::English.word_index(word).unwrap();
+
+        // "yellow" is the 7 entropy bits 0b1111111 followed by the 4 checksum bits
+        let mnemonic = Mnemonic::from_entropy(&[0x7f; 16], Language::English).unwrap();
+        assert!(mnemonic.phrase().ends_with(" yellow"));
+
+        let (last, checksum, bits) = mnemonic.last_word_bit_split();
+        assert_eq!(last, index("yellow"));
+        assert_eq!(bits, 4);
+        assert_eq!(last >> 4, 0x7f);
+        assert_eq!(last & 0xf, u16::from(checksum));
+
+        // with 24 words only 3 entropy bits are left, all zero here
+        let phrase = "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let (last, checksum, bits) = mnemonic.last_word_bit_split();
+        assert_eq!(last, index("bless"));
+        assert_eq!(bits, 8);
+        assert_eq!(last, u16::from(checksum));
+        assert_eq!(checksum, Checksum::compute(&[0x80; 32]));
+    }
+
+    #[test]
+    fn mnemonic_entropy_hamming_distance() {
+        let mnemonic = Mnemonic::from_entropy(&[0x5a; 16], Language::English).unwrap();
+        assert_eq!(mnemonic.entropy_hamming_distance(&mnemonic), Some(0));
+
+        let mut entropy = [0x5a; 16];
+        entropy[0] ^= 0b1000_0001;
+        entropy[15] ^= 0b0001_0000;
+        let other = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+        assert_eq!(mnemonic.entropy_hamming_distance(&other), Some(3));
+        assert_eq!(other.entropy_hamming_distance(&mnemonic), Some(3));
+
+        let longer = Mnemonic::from_entropy(&[0x5a; 32], Language::English).unwrap();
+        assert_eq!(mnemonic.entropy_hamming_distance(&longer), None);
     }
 
     #[test]
     fn mnemonic_format() {
-        let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English);
+        let mnemonic = Mnemonic::new(MnemonicType::Words15, Language::English).unwrap();
 
         assert_eq!(mnemonic.phrase(), format!("{}", mnemonic));
     }
@@ -0,0 +1,221 @@
+//! Entering a phrase one word at a time
+//!
+//! [`MnemonicBuilder`][MnemonicBuilder] is the counterpart of
+//! [`Mnemonic::generate_streaming()`][Mnemonic::generate_streaming()] for entry: every pushed
+//! word is checked against the word list right away and its 11 bits are appended to the
+//! entropy, so that [`MnemonicBuilder::finish()`][MnemonicBuilder::finish()] only has to hash
+//! the entropy once to verify the checksum.
+//!
+//! [MnemonicBuilder]: ./struct.MnemonicBuilder.html
+//! [MnemonicBuilder::finish()]: ./struct.MnemonicBuilder.html#method.finish
+//! [Mnemonic::generate_streaming()]: ./mnemonic/struct.Mnemonic.html#method.generate_streaming
+use failure::Error;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+use crate::checksum::Checksum;
+use crate::error::ErrorKind;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+
+/// The number of words of the longest phrases
+const MAX_WORDS: usize = 24;
+
+/// Builds a [`Mnemonic`][Mnemonic] from words entered one at a time
+///
+/// The entered words and their bits are wiped from memory when the builder is dropped.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Language, MnemonicBuilder};
+///
+/// let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+/// let mut builder = MnemonicBuilder::new(Language::English);
+///
+/// for word in phrase.split(' ') {
+///     builder.push_word(word).unwrap();
+/// }
+///
+/// assert_eq!(builder.finish().unwrap().phrase(), phrase);
+/// ```
+///
+/// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+pub struct MnemonicBuilder {
+    lang: Language,
+    phrase: String,
+    words: usize,
+    /// The complete bytes of the entropy and checksum entered so far
+    bytes: Vec<u8>,
+    /// The bits not filling a byte yet, in the most significant bits
+    pending: u32,
+    pending_bits: usize,
+}
+
+impl MnemonicBuilder {
+    /// Create a builder for a phrase in `lang`
+    pub fn new(lang: Language) -> MnemonicBuilder {
+        MnemonicBuilder {
+            lang,
+            phrase: String::new(),
+            words: 0,
+            bytes: Vec::with_capacity(MAX_WORDS * 11 / 8),
+            pending: 0,
+            pending_bits: 0,
+        }
+    }
+
+    /// Append the next word of the phrase
+    ///
+    /// The word is trimmed and NFKD normalized first. Returns an `Error` of kind
+    /// `ErrorKind::InvalidWord` if it isn't in the word list, and `ErrorKind::InvalidWordLength`
+    /// if the phrase already has 24 words. The builder is left unchanged on error, so the word
+    /// can be entered again.
+    pub fn push_word(&mut self, word: &str) -> Result<(), Error> {
+        if self.words == MAX_WORDS {
+            Err(ErrorKind::InvalidWordLength(MAX_WORDS + 1))?;
+        }
+
+        let mut normalized: String = word.trim().nfkd().collect();
+        let index = self.lang.word_index(&normalized);
+        normalized.zeroize();
+
+        let index = match index {
+            Some(index) => index,
+            None => Err(ErrorKind::InvalidWord)?,
+        };
+
+        if self.words > 0 {
+            self.phrase.push(' ');
+        }
+        self.phrase.push_str(self.lang.wordlist().get_word(index.into()));
+        self.words += 1;
+
+        self.pending |= u32::from(index) << (32 - 11 - self.pending_bits);
+        self.pending_bits += 11;
+
+        while self.pending_bits >= 8 {
+            self.bytes.push((self.pending >> 24) as u8);
+            self.pending <<= 8;
+            self.pending_bits -= 8;
+        }
+
+        Ok(())
+    }
+
+    /// The number of words pushed so far
+    pub fn word_count(&self) -> usize {
+        self.words
+    }
+
+    /// Verify the checksum and build the [`Mnemonic`][Mnemonic]
+    ///
+    /// The entropy is hashed exactly once. Returns an `Error` of kind
+    /// `ErrorKind::InvalidWordLength` if the number of words isn't a valid phrase length, and
+    /// `ErrorKind::InvalidChecksum` if the checksum computed from the entropy differs from the
+    /// one found in the last word.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn finish(mut self) -> Result<Mnemonic, Error> {
+        let mtype = MnemonicType::for_word_count(self.words)?;
+        let entropy_len = mtype.entropy_bytes();
+
+        // move the checksum bits into a byte of their own, unless they already fill one
+        if self.pending_bits != 0 {
+            self.bytes.push((self.pending >> 24) as u8);
+        }
+
+        let expected = Checksum::compute(&self.bytes[..entropy_len]);
+        let actual = self.bytes[entropy_len] >> (8 - mtype.checksum_bits());
+
+        if expected != actual {
+            Err(ErrorKind::InvalidChecksum)?;
+        }
+
+        let phrase = std::mem::take(&mut self.phrase);
+        let entropy = self.bytes[..entropy_len].to_vec();
+
+        Ok(Mnemonic::from_parts(phrase, entropy, self.lang))
+    }
+}
+
+impl Drop for MnemonicBuilder {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.bytes.zeroize();
+        self.pending.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn build(phrase: &str) -> Result<Mnemonic, Error> {
+        let mut builder = MnemonicBuilder::new(Language::English);
+
+        for word in phrase.split(' ') {
+            builder.push_word(word)?;
+        }
+
+        builder.finish()
+    }
+
+    #[test]
+    fn builder_matches_from_phrase() {
+        for &mtype in &[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let built = build(mnemonic.phrase()).unwrap();
+
+            assert_eq!(built.phrase(), mnemonic.phrase());
+            assert_eq!(built.entropy(), mnemonic.entropy());
+        }
+    }
+
+    #[test]
+    fn builder_checksum_mismatch() {
+        // "abandon" carries a checksum of 0, instead of the 0b0011 of "about"
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+
+        match build(phrase).unwrap_err().downcast::<ErrorKind>().unwrap() {
+            ErrorKind::InvalidChecksum => {}
+            kind => panic!("unexpected error: {:?}", kind),
+        }
+
+        // 24 words have a full checksum byte
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo";
+        let kind = build(phrase).unwrap_err().downcast::<ErrorKind>().unwrap();
+
+        assert!(kind.is_checksum_error());
+    }
+
+    #[test]
+    fn builder_invalid_words() {
+        let mut builder = MnemonicBuilder::new(Language::English);
+
+        let err = builder.push_word("zzz").unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidWord));
+        assert_eq!(builder.word_count(), 0);
+
+        builder.push_word(" Zoo ").unwrap_err();
+        builder.push_word(" zoo ").unwrap();
+        assert_eq!(builder.word_count(), 1);
+
+        let err = builder.finish().unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidWordLength(1)));
+
+        let mut builder = MnemonicBuilder::new(Language::English);
+        for _ in 0..24 {
+            builder.push_word("zoo").unwrap();
+        }
+
+        let err = builder.push_word("zoo").unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidWordLength(25)));
+    }
+}
@@ -27,11 +27,12 @@ const ENTROPY_OFFSET: usize = 8;
 ///
 /// [MnemonicType]: ../mnemonic_type/struct.MnemonicType.html
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
-/// [Seed]: ../seed/struct.Seed.html
+/// [Seed]: ../seed/type.Seed.html
 ///
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Default)]
 pub enum MnemonicType {
     //  ... = (entropy_bits << ...)   | checksum_bits
+    #[default]
     Words12 = (128 << ENTROPY_OFFSET) | 4,
     Words15 = (160 << ENTROPY_OFFSET) | 5,
     Words18 = (192 << ENTROPY_OFFSET) | 6,
@@ -52,16 +53,52 @@ impl MnemonicType {
     /// let mnemonic_type = MnemonicType::for_word_count(12).unwrap();
     /// ```
     pub fn for_word_count(size: usize) -> Result<MnemonicType, Error> {
-        let mnemonic_type = match size {
-            12 => MnemonicType::Words12,
-            15 => MnemonicType::Words15,
-            18 => MnemonicType::Words18,
-            21 => MnemonicType::Words21,
-            24 => MnemonicType::Words24,
-            _ => Err(ErrorKind::InvalidWordLength(size))?,
-        };
+        match MnemonicType::from_word_count(size) {
+            Some(mnemonic_type) => Ok(mnemonic_type),
+            None => Err(ErrorKind::InvalidWordLength(size))?,
+        }
+    }
 
-        Ok(mnemonic_type)
+    /// Get a `MnemonicType` for a number of words, or `None` if the BIP39 standard doesn't
+    /// provide for it
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// assert_eq!(MnemonicType::from_word_count(24), Some(MnemonicType::Words24));
+    /// assert_eq!(MnemonicType::from_word_count(13), None);
+    /// ```
+    pub const fn from_word_count(size: usize) -> Option<MnemonicType> {
+        match size {
+            12 => Some(MnemonicType::Words12),
+            15 => Some(MnemonicType::Words15),
+            18 => Some(MnemonicType::Words18),
+            21 => Some(MnemonicType::Words21),
+            24 => Some(MnemonicType::Words24),
+            _ => None,
+        }
+    }
+
+    /// Get a `MnemonicType` for a number of entropy bits, or `None` if the BIP39 standard
+    /// doesn't provide for it
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// assert_eq!(MnemonicType::from_entropy_bits(128), Some(MnemonicType::Words12));
+    /// assert_eq!(MnemonicType::from_entropy_bits(129), None);
+    /// ```
+    pub const fn from_entropy_bits(bits: usize) -> Option<MnemonicType> {
+        match bits {
+            128 => Some(MnemonicType::Words12),
+            160 => Some(MnemonicType::Words15),
+            192 => Some(MnemonicType::Words18),
+            224 => Some(MnemonicType::Words21),
+            256 => Some(MnemonicType::Words24),
+            _ => None,
+        }
     }
 
     /// Get a `MnemonicType` for a mnemonic phrase representing the given key size as bits
@@ -76,16 +113,10 @@ impl MnemonicType {
     /// let mnemonic_type = MnemonicType::for_key_size(128).unwrap();
     /// ```
     pub fn for_key_size(size: usize) -> Result<MnemonicType, Error> {
-        let mnemonic_type = match size {
-            128 => MnemonicType::Words12,
-            160 => MnemonicType::Words15,
-            192 => MnemonicType::Words18,
-            224 => MnemonicType::Words21,
-            256 => MnemonicType::Words24,
-            _ => Err(ErrorKind::InvalidKeysize(size))?,
-        };
-
-        Ok(mnemonic_type)
+        match MnemonicType::from_entropy_bits(size) {
+            Some(mnemonic_type) => Ok(mnemonic_type),
+            None => Err(ErrorKind::InvalidKeysize(size))?,
+        }
     }
 
     /// Get a `MnemonicType` for an existing mnemonic phrase
@@ -128,7 +159,7 @@ impl MnemonicType {
     ///
     /// let total_bits = mnemonic_type.total_bits();
     /// ```
-    pub fn total_bits(&self) -> usize {
+    pub const fn total_bits(&self) -> usize {
         self.entropy_bits() + self.checksum_bits() as usize
     }
 
@@ -145,10 +176,26 @@ impl MnemonicType {
     ///
     /// let entropy_bits = mnemonic_type.entropy_bits();
     /// ```
-    pub fn entropy_bits(&self) -> usize {
+    pub const fn entropy_bits(&self) -> usize {
         (*self as usize) >> ENTROPY_OFFSET
     }
 
+    /// Return the number of entropy bytes
+    ///
+    /// Since this is a `const fn`, it can be used to size stack buffers at compile time.
+    ///
+    /// # Example
+    /// ```
+    /// use bip39::{MnemonicType};
+    ///
+    /// let buffer = [0u8; MnemonicType::Words24.entropy_bytes()];
+    ///
+    /// assert_eq!(buffer.len(), 32);
+    /// ```
+    pub const fn entropy_bytes(&self) -> usize {
+        self.entropy_bits() / 8
+    }
+
     /// Return the number of checksum bits
     ///
     ///
@@ -162,7 +209,7 @@ impl MnemonicType {
     ///
     /// let checksum_bits = mnemonic_type.checksum_bits();
     /// ```
-    pub fn checksum_bits(&self) -> u8 {
+    pub const fn checksum_bits(&self) -> u8 {
         (*self as usize) as u8
     }
 
@@ -177,14 +224,81 @@ impl MnemonicType {
     ///
     /// let word_count = mnemonic_type.word_count();
     /// ```
-    pub fn word_count(&self) -> usize {
+    pub const fn word_count(&self) -> usize {
         self.total_bits() / 11
     }
 }
 
-impl Default for MnemonicType {
-    fn default() -> MnemonicType {
-        MnemonicType::Words12
+/// Get the number of entropy bytes for a mnemonic phrase with `word_count` words
+///
+/// Useful for sizing an entropy buffer when the word count is known at compile time.
+///
+/// # Panics
+///
+/// Panics if the word count isn't provided for by the BIP39 standard, which fails compilation
+/// when evaluated in a const context. For word counts only known at runtime, use
+/// `MnemonicType::from_word_count(word_count).map(|mtype| mtype.entropy_bytes())` instead.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{entropy_bytes, Mnemonic, Language};
+///
+/// let entropy = [0x42; entropy_bytes(24)];
+/// let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+///
+/// assert_eq!(mnemonic.words().count(), 24);
+/// ```
+pub const fn entropy_bytes(word_count: usize) -> usize {
+    match MnemonicType::from_word_count(word_count) {
+        Some(mtype) => mtype.entropy_bytes(),
+        None => panic!("invalid number of words for a mnemonic phrase"),
+    }
+}
+
+/// Estimate how many candidate phrases a recovery has to check when `known_words` of the
+/// `total_words` words of a phrase are known
+///
+/// Each unknown word can be any of the 2048 words of the word list, but only one in
+/// 2<sup>checksum bits</sup> of the resulting phrases has a valid checksum, so only those are
+/// counted. The result saturates at `u128::MAX`, which is reached with 13 or more unknown words,
+/// or with all 12 words of a 12 word phrase unknown: 2<sup>128</sup> candidates don't fit in a
+/// `u128`.
+///
+/// # Panics
+///
+/// Panics if `total_words` isn't provided for by the BIP39 standard, or if `known_words` is
+/// larger than `total_words`.
+///
+/// # Example
+///
+/// ```
+/// use bip39::estimate_search_space;
+///
+/// // a single missing word of a 12 word phrase is one of 2048 / 2^4 valid candidates
+/// assert_eq!(estimate_search_space(11, 12), 128);
+/// assert_eq!(estimate_search_space(12, 12), 1);
+/// ```
+pub fn estimate_search_space(known_words: usize, total_words: usize) -> u128 {
+    let mtype = match MnemonicType::from_word_count(total_words) {
+        Some(mtype) => mtype,
+        None => panic!("invalid number of words for a mnemonic phrase"),
+    };
+
+    assert!(known_words <= total_words, "more known words than words in the phrase");
+
+    let unknown_words = total_words - known_words;
+
+    if unknown_words == 0 {
+        return 1;
+    }
+
+    let bits = unknown_words * 11 - mtype.checksum_bits() as usize;
+
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        1 << bits
     }
 }
 
@@ -199,6 +313,57 @@ impl fmt::Display for MnemonicType {
     }
 }
 
+/// A named security level for new mnemonics, for UIs addressing non-technical users
+///
+/// Each level stands for a [`MnemonicType`][MnemonicType], which remains the precise type used
+/// everywhere else. BIP39 phrases hold at most 256 bits of entropy, so there is nothing above
+/// `High`.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{MnemonicType, SecurityLevel};
+///
+/// assert_eq!(SecurityLevel::High.mnemonic_type(), MnemonicType::Words24);
+/// assert_eq!(SecurityLevel::Standard.to_string(), "Standard (128-bit)");
+/// ```
+///
+/// [MnemonicType]: ../mnemonic_type/struct.MnemonicType.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum SecurityLevel {
+    /// 128 bits of entropy, a 12 word phrase
+    #[default]
+    Standard,
+    /// 256 bits of entropy, a 24 word phrase
+    High,
+}
+
+impl SecurityLevel {
+    /// The [`MnemonicType`][MnemonicType] of the level
+    ///
+    /// [MnemonicType]: ../mnemonic_type/struct.MnemonicType.html
+    pub const fn mnemonic_type(&self) -> MnemonicType {
+        match self {
+            SecurityLevel::Standard => MnemonicType::Words12,
+            SecurityLevel::High => MnemonicType::Words24,
+        }
+    }
+
+    /// The name of the level, without its number of bits
+    pub fn name(&self) -> &'static str {
+        match self {
+            SecurityLevel::Standard => "Standard",
+            SecurityLevel::High => "High",
+        }
+    }
+}
+
+impl fmt::Display for SecurityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}-bit)", self.name(), self.mnemonic_type().entropy_bits())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -221,6 +386,89 @@ mod test {
         assert_eq!(MnemonicType::Words24.entropy_bits(), 256);
     }
 
+    #[test]
+    fn entropy_bytes() {
+        const BYTES: usize = MnemonicType::Words24.entropy_bytes();
+
+        assert_eq!([0u8; BYTES].len(), 32);
+        assert_eq!(MnemonicType::Words12.entropy_bytes(), 16);
+        assert_eq!(MnemonicType::Words15.entropy_bytes(), 20);
+        assert_eq!(MnemonicType::Words18.entropy_bytes(), 24);
+        assert_eq!(MnemonicType::Words21.entropy_bytes(), 28);
+        assert_eq!(MnemonicType::Words24.entropy_bytes(), 32);
+    }
+
+    #[test]
+    fn from_word_count() {
+        let all = [
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ];
+
+        for &mtype in &all {
+            assert_eq!(MnemonicType::from_word_count(mtype.word_count()), Some(mtype));
+            assert_eq!(MnemonicType::from_entropy_bits(mtype.entropy_bits()), Some(mtype));
+        }
+
+        for &invalid in &[0, 11, 13, 25, 33] {
+            assert_eq!(MnemonicType::from_word_count(invalid), None);
+        }
+
+        for &invalid in &[0, 127, 129, 264, 512] {
+            assert_eq!(MnemonicType::from_entropy_bits(invalid), None);
+        }
+    }
+
+    #[test]
+    fn entropy_bytes_for_word_count() {
+        const BYTES: usize = super::entropy_bytes(15);
+
+        assert_eq!([0u8; BYTES].len(), 20);
+        assert_eq!(super::entropy_bytes(12), 16);
+        assert_eq!(super::entropy_bytes(18), 24);
+        assert_eq!(super::entropy_bytes(21), 28);
+        assert_eq!(super::entropy_bytes(24), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid number of words")]
+    fn entropy_bytes_for_invalid_word_count() {
+        super::entropy_bytes(13);
+    }
+
+    #[test]
+    fn search_space() {
+        assert_eq!(estimate_search_space(12, 12), 1);
+        assert_eq!(estimate_search_space(11, 12), 128);
+        assert_eq!(estimate_search_space(23, 24), 8);
+        assert_eq!(estimate_search_space(10, 12), 2048 * 128);
+        assert_eq!(estimate_search_space(12, 24), 1 << 124);
+        assert_eq!(estimate_search_space(11, 24), u128::MAX);
+        assert_eq!(estimate_search_space(0, 24), u128::MAX);
+
+        // 12 unknown words saturate a 12 word phrase, but not a longer one
+        assert_eq!(estimate_search_space(0, 12), u128::MAX);
+        assert_eq!(estimate_search_space(3, 15), 1 << 127);
+        assert_eq!(estimate_search_space(2, 15), u128::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "more known words")]
+    fn search_space_too_many_known_words() {
+        estimate_search_space(13, 12);
+    }
+
+    #[test]
+    fn security_levels() {
+        assert_eq!(SecurityLevel::default(), SecurityLevel::Standard);
+        assert_eq!(SecurityLevel::Standard.mnemonic_type(), MnemonicType::Words12);
+        assert_eq!(SecurityLevel::High.mnemonic_type(), MnemonicType::Words24);
+        assert_eq!(SecurityLevel::High.to_string(), "High (256-bit)");
+    }
+
     #[test]
     fn checksum_bits() {
         assert_eq!(MnemonicType::Words12.checksum_bits(), 4);
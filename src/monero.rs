@@ -0,0 +1,112 @@
+//! Detection of Monero seeds, with the `monero` feature
+//!
+//! Monero seeds are 25 words long, easily mistaken for BIP39 phrases but not compatible with
+//! them: they use different word lists and encode the key differently. Their last word is a
+//! checksum, a copy of one of the first 24 words picked by the CRC32 of the unique prefixes of
+//! all 24, and that alone identifies a Monero seed without its word lists.
+//! [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] uses it to return an `Error` of kind
+//! `ErrorKind::LikelyMoneroSeed` instead of a generic failure.
+//!
+//! Nothing beyond detection is provided, Monero seeds can't be decoded with this crate.
+//!
+//! [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+
+/// The number of words of a Monero seed, including the checksum word
+const MONERO_WORDS: usize = 25;
+
+/// The unique prefix lengths of the Monero word lists: 1 for Chinese, 3 for English and
+/// Japanese, 4 for the others
+const PREFIX_LENGTHS: [usize; 3] = [3, 4, 1];
+
+/// Whether `phrase` looks like a 25 word Monero seed
+///
+/// The words must be separated by single spaces, as in a normalized phrase. Only the checksum
+/// word is checked, so seeds in the Monero word lists with accented words may be missed once
+/// normalized, but random BIP39 words pass the check very rarely.
+///
+/// # Example
+///
+/// ```
+/// use bip39::monero;
+///
+/// let seed = "sequence atlas unveil summon pebbles tuesday beer rudely snake rockets different fuselage woven tagged bested dented vegan hover rapid fawns obvious muppet randomly seasons randomly";
+///
+/// assert!(monero::is_likely_monero_seed(seed));
+/// ```
+pub fn is_likely_monero_seed(phrase: &str) -> bool {
+    let words: Vec<&str> = phrase.split(' ').collect();
+
+    if words.len() != MONERO_WORDS {
+        return false;
+    }
+
+    let (checksum_word, words) = words.split_last().expect("25 words");
+
+    PREFIX_LENGTHS.iter().any(|&len| {
+        let prefixes: String = words.iter().flat_map(|word| word.chars().take(len)).collect();
+        let index = crc32(prefixes.as_bytes()) as usize % words.len();
+
+        words[index] == *checksum_word
+    })
+}
+
+/// The CRC32 (IEEE 802.3) of `data`, which Monero uses for its checksum word
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= u32::from(byte);
+
+        for _ in 0..8 {
+            let mask = 0u32.wrapping_sub(crc & 1);
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::language::Language;
+    use crate::mnemonic::Mnemonic;
+
+    /// The example seed of the Monero documentation
+    const MONERO_SEED: &str = "sequence atlas unveil summon pebbles tuesday beer rudely snake rockets different fuselage woven tagged bested dented vegan hover rapid fawns obvious muppet randomly seasons randomly";
+
+    #[test]
+    fn monero_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn monero_seed_detected() {
+        assert!(is_likely_monero_seed(MONERO_SEED));
+
+        // any other checksum word fails
+        let tampered = MONERO_SEED.replace("seasons randomly", "seasons sequence");
+        assert!(!is_likely_monero_seed(&tampered));
+
+        let bip39 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(!is_likely_monero_seed(bip39));
+        assert!(!is_likely_monero_seed(""));
+    }
+
+    #[test]
+    fn monero_seed_from_phrase() {
+        let err = Mnemonic::from_phrase(MONERO_SEED, Language::English).unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::LikelyMoneroSeed));
+
+        // detection runs on the normalized phrase
+        let spaced = MONERO_SEED.replace(' ', "  ");
+        let err = Mnemonic::from_phrase(&spaced, Language::English).unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::LikelyMoneroSeed));
+
+        let tampered = MONERO_SEED.replace("seasons randomly", "seasons sequence");
+        let err = Mnemonic::from_phrase(&tampered, Language::English).unwrap_err();
+        assert!(!matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::LikelyMoneroSeed));
+    }
+}
@@ -0,0 +1,149 @@
+//! Tools for debugging Unicode normalization
+//!
+//! BIP39 prescribes NFKD normalization for both the phrase and the password before a seed is
+//! derived. When two wallets disagree on the seed of the same phrase, one of them usually skipped
+//! or botched this step, and [`diff()`][diff()] shows exactly which characters are affected.
+//! Unusual word separators cause similar trouble, [`analyze_separators()`][analyze_separators()]
+//! lists them.
+//!
+//! [diff()]: ./fn.diff.html
+//! [analyze_separators()]: ./fn.analyze_separators.html
+use unicode_normalization::UnicodeNormalization;
+
+/// List the characters of `input` that NFKD normalization changes
+///
+/// Every entry is `(position, original, normalized)`, where `position` is the index of the
+/// original character counted in `char`s, not bytes. A character that decomposes into several
+/// characters, such as `é` into `e` and a combining acute accent, gets one entry for each of them,
+/// all with the same position. An empty result means normalization leaves `input` untouched.
+///
+/// Characters are normalized one at a time, so the reordering of adjacent combining marks that
+/// NFKD may also do isn't reported.
+///
+/// # Example
+///
+/// ```
+/// use bip39::normalization;
+///
+/// // a full-width "Ａ" as typed with some Asian input methods, and a precomposed "é"
+/// let changes = normalization::diff("Ａbé");
+///
+/// assert_eq!(changes, [(0, 'Ａ', 'A'), (2, 'é', 'e'), (2, 'é', '\u{301}')]);
+/// ```
+pub fn diff(input: &str) -> Vec<(usize, char, char)> {
+    let mut changes = Vec::new();
+
+    for (position, original) in input.chars().enumerate() {
+        let normalized: Vec<char> = original.nfkd().collect();
+
+        if normalized != [original] {
+            changes.extend(normalized.into_iter().map(|c| (position, original, c)));
+        }
+    }
+
+    changes
+}
+
+/// List the distinct characters separating the words of `phrase`, in order of first appearance
+///
+/// A separator is any whitespace character, such as a space, a tab, a non-breaking space
+/// (U+00A0) or the ideographic space (U+3000) of Japanese phrases, as well as a comma or a
+/// zero-width space (U+200B). [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()] turns them
+/// all into single spaces, but other wallets may not, so anything other than `' '` is worth a
+/// warning.
+///
+/// # Example
+///
+/// ```
+/// use bip39::normalization;
+///
+/// let separators = normalization::analyze_separators("park remain\u{a0}person, kitchen");
+///
+/// assert_eq!(separators, [' ', '\u{a0}', ',']);
+/// ```
+///
+/// [Mnemonic::from_phrase()]: ../mnemonic/struct.Mnemonic.html#method.from_phrase
+pub fn analyze_separators(phrase: &str) -> Vec<char> {
+    let mut separators = Vec::new();
+
+    for c in phrase.chars() {
+        if (c.is_whitespace() || c == ',' || c == '\u{200B}') && !separators.contains(&c) {
+            separators.push(c);
+        }
+    }
+
+    separators
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diff_normalized_input() {
+        assert!(diff("").is_empty());
+        assert!(diff("abandon ability able").is_empty());
+        assert!(diff("e\u{301}").is_empty());
+    }
+
+    #[test]
+    fn diff_full_width() {
+        assert_eq!(
+            diff("ｐａｒｋ x"),
+            [(0, 'ｐ', 'p'), (1, 'ａ', 'a'), (2, 'ｒ', 'r'), (3, 'ｋ', 'k')]
+        );
+    }
+
+    #[test]
+    fn diff_password() {
+        // the password of the Japanese test vectors in BIP39
+        let changes = diff("㍍ガバヴァぱばぐゞちぢ十人十色");
+
+        let mut positions: Vec<usize> = changes.iter().map(|&(position, _, _)| position).collect();
+        positions.dedup();
+
+        assert_eq!(positions, [0, 1, 2, 3, 5, 6, 7, 8, 10]);
+        assert_eq!(
+            changes[..5],
+            [(0, '㍍', 'メ'), (0, '㍍', 'ー'), (0, '㍍', 'ト'), (0, '㍍', 'ル'), (1, 'ガ', 'カ')]
+        );
+    }
+
+    #[test]
+    fn separators() {
+        assert!(analyze_separators("").is_empty());
+        assert!(analyze_separators("abandon").is_empty());
+        assert_eq!(analyze_separators("abandon ability able"), [' ']);
+        assert_eq!(
+            analyze_separators("abandon\u{a0}ability\u{a0} able"),
+            ['\u{a0}', ' ']
+        );
+        assert_eq!(
+            analyze_separators("あいこくしん\u{3000}あいさつ\tあいだ,あおぞら\u{200B}あかちゃん"),
+            ['\u{3000}', '\t', ',', '\u{200B}']
+        );
+    }
+
+    #[test]
+    fn diff_matches_nfkd() {
+        let input = "ｐａｒｋ ﬁ½ 한국어 Ä";
+        let changes = diff(input);
+        let mut rebuilt = String::new();
+
+        for (position, c) in input.chars().enumerate() {
+            let replacement: String = changes
+                .iter()
+                .filter(|&&(p, _, _)| p == position)
+                .map(|&(_, _, n)| n)
+                .collect();
+
+            if replacement.is_empty() {
+                rebuilt.push(c);
+            } else {
+                rebuilt.push_str(&replacement);
+            }
+        }
+
+        assert_eq!(rebuilt, input.nfkd().collect::<String>());
+    }
+}
@@ -0,0 +1,253 @@
+//! An extra parity word for detecting transcription errors in a phrase
+//!
+//! **This is not part of BIP39.** The parity word is the sum of the word list indices of all the
+//! words of the phrase modulo 2048, written down separately from the phrase. When one word of the
+//! phrase is miscopied the sum no longer matches, and the difference tells by how much the index
+//! of the wrong word is off.
+//!
+//! The code works over the 11 bit word indices rather than GF(256) bytes, which don't line up
+//! with words. It doesn't locate the wrong word on its own, and no single check word could: it
+//! holds 2048 values, fewer than the 12 × 2047 possible single word errors of the shortest
+//! phrase. Instead, every position is tried with the index corrected by the difference, and only
+//! the substitutions that also pass the BIP39 checksum are kept. The right one is always among
+//! them, but a wrong one passes the checksum with probability 1 in 2<sup>checksum bits</sup>, so
+//! a 12 word phrase gets about 0.7 wrong candidates on average, and a 24 word phrase about 0.1.
+//! A single candidate is therefore likely, not certain, to be the right correction, and two or
+//! more wrong words can't be corrected at all.
+use crate::error::ErrorKind;
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use failure::Error;
+use unicode_normalization::UnicodeNormalization;
+
+/// The outcome of [`Mnemonic::verify_with_parity()`][Mnemonic::verify_with_parity()]
+///
+/// [Mnemonic::verify_with_parity()]: ./mnemonic/struct.Mnemonic.html#method.verify_with_parity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParityCheck {
+    /// The parity word matches and the phrase is a valid mnemonic
+    Valid,
+    /// The phrase doesn't match the parity word or has an invalid checksum
+    Corrupted {
+        /// Every `(position, word)` pair that, when substituted into the phrase, makes it match
+        /// both the parity word and the BIP39 checksum. A single entry locates the wrong word,
+        /// several entries narrow it down, and no entries mean there is more than one wrong word
+        /// (or the parity word itself is wrong).
+        candidates: Vec<(usize, &'static str)>,
+    },
+}
+
+impl Mnemonic {
+    /// Compute the non-standard parity word of this phrase, see
+    /// [`Mnemonic::verify_with_parity()`][Mnemonic::verify_with_parity()]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let mnemonic = Mnemonic::from_phrase("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", Language::English).unwrap();
+    ///
+    /// // the indices are eleven times 0 and 3
+    /// assert_eq!(mnemonic.parity_word(), "about");
+    /// ```
+    ///
+    /// [Mnemonic::verify_with_parity()]: ./mnemonic/struct.Mnemonic.html#method.verify_with_parity
+    pub fn parity_word(&self) -> &'static str {
+        let lang = self.language();
+        let sum = self
+            .words()
+            .map(|word| {
+                lang.word_index(word)
+                    .expect("words of a mnemonic are in the word list")
+            })
+            .fold(0u16, |sum, index| (sum + index) % 2048);
+
+        lang.word(sum).expect("index is below 2048")
+    }
+
+    /// Check the words of a phrase against the parity word written down next to it
+    ///
+    /// **This is not part of BIP39**, see [`Mnemonic::parity_word()`][Mnemonic::parity_word()].
+    /// Any single wrong word is detected. A single word that isn't in the word list is recovered
+    /// from the parity word directly.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::InvalidWordLength` if the number of words isn't
+    /// valid, and `ErrorKind::InvalidWord` if the parity word or more than one word of the phrase
+    /// isn't in the word list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, ParityCheck};
+    ///
+    /// // "about" was miscopied as "above"
+    /// let words = ["abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "abandon", "above"];
+    /// let check = Mnemonic::verify_with_parity(&words, "about", Language::English).unwrap();
+    ///
+    /// // replacing the 11th word with "zoo" happens to fit the parity and checksum too
+    /// assert_eq!(check, ParityCheck::Corrupted { candidates: vec![(10, "zoo"), (11, "about")] });
+    /// ```
+    ///
+    /// [Mnemonic::parity_word()]: ./mnemonic/struct.Mnemonic.html#method.parity_word
+    pub fn verify_with_parity(
+        words: &[&str],
+        parity: &str,
+        lang: Language,
+    ) -> Result<ParityCheck, Error> {
+        MnemonicType::for_word_count(words.len())?;
+
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| word.trim().nfkd().collect())
+            .collect();
+        let indices: Vec<Option<u16>> = words.iter().map(|word| lang.word_index(word)).collect();
+
+        let parity = match lang.word_index(&parity.trim().nfkd().collect::<String>()) {
+            Some(index) => index,
+            None => Err(ErrorKind::InvalidWord)?,
+        };
+
+        let sum = indices
+            .iter()
+            .flatten()
+            .fold(0u16, |sum, index| (sum + index) % 2048);
+
+        let mut unknown = indices
+            .iter()
+            .enumerate()
+            .filter(|(_, index)| index.is_none());
+
+        let candidate = |position: usize, index: u16| {
+            let word = lang.word(index).expect("index is below 2048");
+            let mut phrase: Vec<&str> = words.iter().map(String::as_str).collect();
+            phrase[position] = word;
+
+            match Mnemonic::validate(&phrase.join(" "), lang) {
+                Ok(()) => Some((position, word)),
+                Err(_) => None,
+            }
+        };
+
+        let candidates = match (unknown.next(), unknown.next()) {
+            (Some(_), Some(_)) => Err(ErrorKind::InvalidWord)?,
+            // the parity determines the missing index
+            (Some((position, _)), None) => candidate(position, (parity + 2048 - sum) % 2048)
+                .into_iter()
+                .collect(),
+            (None, None) if sum == parity => {
+                if Mnemonic::validate(&words.join(" "), lang).is_ok() {
+                    return Ok(ParityCheck::Valid);
+                }
+
+                Vec::new()
+            }
+            // a single wrong word is off by the difference between the sum and the parity
+            (None, _) => {
+                let offset = (sum + 2048 - parity) % 2048;
+
+                indices
+                    .iter()
+                    .flatten()
+                    .enumerate()
+                    .filter_map(|(position, &index)| {
+                        candidate(position, (index + 2048 - offset) % 2048)
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(ParityCheck::Corrupted { candidates })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PHRASE: &str = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+    fn words() -> Vec<&'static str> {
+        PHRASE.split(' ').collect()
+    }
+
+    #[test]
+    fn parity_valid() {
+        for &mtype in &[MnemonicType::Words12, MnemonicType::Words24] {
+            let mnemonic = Mnemonic::new(mtype, Language::English).unwrap();
+            let words: Vec<&str> = mnemonic.words().collect();
+
+            let check =
+                Mnemonic::verify_with_parity(&words, mnemonic.parity_word(), Language::English);
+
+            assert_eq!(check.unwrap(), ParityCheck::Valid);
+        }
+    }
+
+    #[test]
+    fn parity_detects_single_errors() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let parity = mnemonic.parity_word();
+
+        for position in 0..12 {
+            for &index in &[0u16, 1, 1000, 2047] {
+                let mut words = words();
+                let original = words[position];
+                let replacement = Language::English.word(index).unwrap();
+
+                if replacement == original {
+                    continue;
+                }
+
+                words[position] = replacement;
+
+                match Mnemonic::verify_with_parity(&words, parity, Language::English).unwrap() {
+                    ParityCheck::Corrupted { candidates } => {
+                        assert!(candidates.contains(&(position, original)))
+                    }
+                    ParityCheck::Valid => panic!("undetected error at position {}", position),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parity_recovers_unknown_word() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+
+        let mut words = words();
+        words[4] = "eihgt";
+
+        let check = Mnemonic::verify_with_parity(&words, mnemonic.parity_word(), Language::English);
+
+        assert_eq!(
+            check.unwrap(),
+            ParityCheck::Corrupted {
+                candidates: vec![(4, "eight")]
+            }
+        );
+
+        words[5] = "fiath";
+
+        assert!(
+            Mnemonic::verify_with_parity(&words, mnemonic.parity_word(), Language::English)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parity_wrong_parity_word() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE, Language::English).unwrap();
+        let index = Language::English
+            .word_index(mnemonic.parity_word())
+            .unwrap();
+        let wrong_parity = Language::English.word((index + 1) % 2048).unwrap();
+
+        assert!(Mnemonic::verify_with_parity(&words(), "notaword", Language::English).is_err());
+        assert_ne!(
+            Mnemonic::verify_with_parity(&words(), wrong_parity, Language::English).unwrap(),
+            ParityCheck::Valid
+        );
+    }
+}
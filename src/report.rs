@@ -0,0 +1,360 @@
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+
+/// Diagnostic information about a single word of a phrase, see [`explain()`][explain()]
+///
+/// [explain()]: ./fn.explain.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordReport {
+    /// The NFKD normalized word
+    pub word: String,
+    /// Index of the word in the word list, `None` if the word isn't in the list
+    pub index: Option<u16>,
+}
+
+/// Diagnostic information about a mnemonic phrase, see [`explain()`][explain()]
+///
+/// [explain()]: ./fn.explain.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicReport {
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// Validity and index of each word, in order
+    pub words: Vec<WordReport>,
+    /// Whether the checksum passes, always `false` if any word is invalid or the word count
+    /// doesn't match one of the standard BIP39 phrase lengths
+    pub checksum_valid: bool,
+    /// Languages whose word list contains every word of the phrase
+    pub language_candidates: Vec<Language>,
+    /// Number of entropy bits for the word count, `None` if the word count isn't standard
+    pub entropy_bits: Option<usize>,
+}
+
+impl MnemonicReport {
+    /// Whether the phrase is a valid mnemonic for the language it was checked against
+    pub fn is_valid(&self) -> bool {
+        self.checksum_valid
+    }
+}
+
+/// Run all the checks performed when parsing a phrase and collect the results into a
+/// [`MnemonicReport`][MnemonicReport] instead of stopping at the first error
+///
+/// The phrase is normalized the same way [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()]
+/// does it. This never panics on malformed input.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{explain, Language};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let report = explain(phrase, Language::English);
+///
+/// assert_eq!(report.word_count, 12);
+/// assert_eq!(report.entropy_bits, Some(128));
+/// assert!(report.checksum_valid);
+/// ```
+///
+/// [MnemonicReport]: ./struct.MnemonicReport.html
+/// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+pub fn explain(phrase: &str, lang: Language) -> MnemonicReport {
+    let normalized = Mnemonic::normalize_phrase(phrase);
+    let wordmap = lang.wordmap();
+
+    let words: Vec<WordReport> = normalized
+        .split_whitespace()
+        .map(|word| WordReport {
+            word: word.to_owned(),
+            index: wordmap.get_bits(word).ok().map(u16::from),
+        })
+        .collect();
+
+    let word_count = words.len();
+    let entropy_bits = MnemonicType::for_word_count(word_count)
+        .ok()
+        .map(|mtype| mtype.entropy_bits());
+
+    let checksum_valid = entropy_bits.is_some()
+        && words.iter().all(|word| word.index.is_some())
+        && Mnemonic::from_phrase_lenient(&normalized, lang)
+            .map(|(_, checksum_valid)| checksum_valid)
+            .unwrap_or(false);
+
+    let language_candidates = if word_count == 0 {
+        Vec::new()
+    } else {
+        Language::all()
+            .iter()
+            .cloned()
+            .filter(|lang| {
+                let wordmap = lang.wordmap();
+
+                words.iter().all(|word| wordmap.contains_word(&word.word))
+            })
+            .collect()
+    };
+
+    MnemonicReport {
+        word_count,
+        words,
+        checksum_valid,
+        language_candidates,
+        entropy_bits,
+    }
+}
+
+/// Heuristics hinting at weak entropy behind a mnemonic, see
+/// [`Mnemonic::entropy_strength_report()`][Mnemonic::entropy_strength_report()]
+///
+/// [Mnemonic::entropy_strength_report()]: ./mnemonic/struct.Mnemonic.html#method.entropy_strength_report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrengthReport {
+    /// Whether every bit of the entropy is zero
+    pub all_zeros: bool,
+    /// Whether every bit of the entropy is one
+    pub all_ones: bool,
+    /// Number of words that repeat an earlier word of the phrase
+    pub repeated_words: usize,
+    /// Number of distinct byte values in the entropy, close to its length for random entropy
+    pub distinct_bytes: usize,
+    /// Length of the entropy in bytes
+    pub entropy_len: usize,
+    /// Estimated length of the entropy after compression, in bytes, close to its length for
+    /// random entropy
+    pub compressed_len: usize,
+}
+
+impl StrengthReport {
+    /// Whether any of the heuristics flags the entropy as suspiciously weak
+    ///
+    /// That is all-zero or all-one entropy, too many repeated words, fewer distinct byte values
+    /// than half the entropy length, or entropy compressing to less than three quarters of its
+    /// length. The number of repeated words allowed grows with the length of the phrase, from 4
+    /// for 12 words to 8 for 24 words, so that random entropy trips it with a probability below
+    /// 10<sup>-10</sup>. Random entropy almost never triggers the other heuristics either.
+    pub fn is_suspicious(&self) -> bool {
+        self.all_zeros
+            || self.all_ones
+            || self.repeated_words > self.entropy_len / 4
+            || self.distinct_bytes * 2 < self.entropy_len
+            || self.compressed_len * 4 < self.entropy_len * 3
+    }
+}
+
+impl Mnemonic {
+    /// Check the entropy of this mnemonic with several weakness heuristics at once
+    ///
+    /// This is advisory only, for warning users that a phrase looks suspiciously weak, e.g.
+    /// because it was made up by hand or generated by a broken RNG. Every entropy is equally
+    /// valid in BIP39, and strong entropy can occasionally look weak, so nothing is ever
+    /// rejected because of the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let report = mnemonic.entropy_strength_report();
+    ///
+    /// assert!(report.all_zeros);
+    /// assert_eq!(report.repeated_words, 10);
+    /// assert!(report.is_suspicious());
+    /// ```
+    pub fn entropy_strength_report(&self) -> StrengthReport {
+        let entropy = self.entropy();
+
+        let mut seen = [false; 256];
+        for &byte in entropy {
+            seen[byte as usize] = true;
+        }
+
+        StrengthReport {
+            all_zeros: entropy.iter().all(|&byte| byte == 0),
+            all_ones: entropy.iter().all(|&byte| byte == 0xff),
+            repeated_words: self
+                .repeated_words()
+                .iter()
+                .map(|&(_, count)| count - 1)
+                .sum(),
+            distinct_bytes: seen.iter().filter(|&&seen| seen).count(),
+            entropy_len: entropy.len(),
+            compressed_len: compressed_len(entropy),
+        }
+    }
+}
+
+/// Estimate the compressed length of `data` with a greedy LZ77 scheme, counting a literal byte
+/// as 1 byte and a back reference to 3 or more earlier bytes as 2 bytes
+fn compressed_len(data: &[u8]) -> usize {
+    let mut len = 0;
+    let mut position = 0;
+
+    while position < data.len() {
+        // the longest match starting before `position`, possibly overlapping it
+        let longest = (0..position)
+            .map(|start| {
+                data[position..]
+                    .iter()
+                    .zip(&data[start..])
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
+
+        if longest >= 3 {
+            len += 2;
+            position += longest;
+        } else {
+            len += 1;
+            position += 1;
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn explain_valid() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let report = explain(phrase, Language::English);
+
+        assert!(report.is_valid());
+        assert_eq!(report.word_count, 12);
+        assert_eq!(report.entropy_bits, Some(128));
+        assert_eq!(report.language_candidates, vec![Language::English]);
+        assert_eq!(
+            report.words[0],
+            WordReport {
+                word: "crop".into(),
+                index: Some(415),
+            }
+        );
+    }
+
+    #[test]
+    fn explain_invalid() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrantt";
+
+        let report = explain(phrase, Language::English);
+
+        assert!(!report.checksum_valid);
+        assert_eq!(report.entropy_bits, Some(128));
+        assert!(report.language_candidates.is_empty());
+        assert_eq!(report.words[11].index, None);
+        assert!(report.words[..11].iter().all(|word| word.index.is_some()));
+
+        let report = explain("crop cash unable", Language::English);
+
+        assert!(!report.checksum_valid);
+        assert_eq!(report.word_count, 3);
+        assert_eq!(report.entropy_bits, None);
+    }
+
+    #[test]
+    fn strength_report_random() {
+        let phrase = "vessel ladder alter error federal sibling chat ability sun glass valve picture";
+        let report = Mnemonic::from_phrase(phrase, Language::English)
+            .unwrap()
+            .entropy_strength_report();
+
+        assert_eq!(
+            report,
+            StrengthReport {
+                all_zeros: false,
+                all_ones: false,
+                repeated_words: 0,
+                distinct_bytes: 16,
+                entropy_len: 16,
+                compressed_len: 16,
+            }
+        );
+        assert!(!report.is_suspicious());
+
+        // seeded, so that the test never depends on the luck of the draw
+        let mut rng = StdRng::seed_from_u64(206);
+
+        for &mtype in &[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ] {
+            for _ in 0..200 {
+                let mnemonic = Mnemonic::from_source(&mut rng, mtype, Language::English).unwrap();
+
+                let report = mnemonic.entropy_strength_report();
+                assert!(!report.is_suspicious(), "{}", mnemonic.phrase());
+            }
+        }
+    }
+
+    #[test]
+    fn strength_report_repeated_words_threshold() {
+        let report = |repeated_words, entropy_len| StrengthReport {
+            all_zeros: false,
+            all_ones: false,
+            repeated_words,
+            distinct_bytes: entropy_len,
+            entropy_len,
+            compressed_len: entropy_len,
+        };
+
+        assert!(!report(4, 16).is_suspicious());
+        assert!(report(5, 16).is_suspicious());
+        assert!(!report(8, 32).is_suspicious());
+        assert!(report(9, 32).is_suspicious());
+    }
+
+    #[test]
+    fn strength_report_weak() {
+        let zeros = Mnemonic::from_entropy(&[0; 32], Language::English)
+            .unwrap()
+            .entropy_strength_report();
+
+        assert!(zeros.all_zeros);
+        assert!(!zeros.all_ones);
+        assert_eq!(zeros.repeated_words, 22);
+        assert_eq!(zeros.distinct_bytes, 1);
+        assert_eq!(zeros.compressed_len, 3);
+        assert!(zeros.is_suspicious());
+
+        let ones = Mnemonic::from_entropy(&[0xff; 16], Language::English)
+            .unwrap()
+            .entropy_strength_report();
+        assert!(ones.all_ones);
+        assert!(ones.is_suspicious());
+
+        // a short pattern repeated over and over, with nothing else to flag it
+        let pattern: Vec<u8> = (0..32).map(|i| [0x3c, 0xa1, 0x5e, 0x97][i % 4]).collect();
+        let report = Mnemonic::from_entropy(&pattern, Language::English)
+            .unwrap()
+            .entropy_strength_report();
+        assert_eq!(report.distinct_bytes, 4);
+        assert_eq!(report.compressed_len, 6);
+        assert!(report.is_suspicious());
+    }
+
+    #[test]
+    fn explain_empty() {
+        for phrase in &["", "   ", "\u{3000}"] {
+            let report = explain(phrase, Language::English);
+
+            assert_eq!(report.word_count, 0);
+            assert!(!report.checksum_valid);
+            assert!(report.language_candidates.is_empty());
+        }
+    }
+}
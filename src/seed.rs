@@ -1,7 +1,17 @@
+use std::convert::TryFrom;
 use std::fmt;
 use unicode_normalization::UnicodeNormalization;
-use crate::crypto::pbkdf2;
+use zeroize::Zeroize;
+use failure::Error;
+use subtle::ConstantTimeEq;
+use crate::crypto::{
+    hmac_sha512, pbkdf2, pbkdf2_body, pbkdf2_into, pbkdf2_many, pbkdf2_prf, pbkdf2_rounds,
+    pbkdf2_with, Pbkdf2Hash, PBKDF2_ROUNDS,
+};
+use crate::error::ErrorKind;
+use crate::language::Language;
 use crate::mnemonic::Mnemonic;
+use crate::util::from_hex;
 
 /// The secret value used to derive HD wallet addresses from a [`Mnemonic`][Mnemonic] phrase.
 ///
@@ -13,53 +23,1098 @@ use crate::mnemonic::Mnemonic;
 /// HD wallet addresses using another crate (deriving HD wallet addresses is outside the scope of this
 /// crate and the BIP39 standard).
 ///
+/// The seed bytes are wiped from memory when the [`Seed`][Seed] is dropped, clones hold their
+/// own copy of the bytes. With the `mlock` feature they are also kept in locked memory, which the
+/// operating system never writes to swap, see [`Seed::is_locked()`][Seed::is_locked()].
+///
+/// The length of the seed in bytes is part of its type. BIP39 seeds are 64 bytes long, which is
+/// the [`Seed`][Seed] alias used throughout the crate; other lengths such as `GenericSeed<32>`
+/// are only meant for protocols that standardize on them.
+///
+/// Serializing or debug printing a seed writes `"<redacted>"` in place of the bytes, so that
+/// deriving `Serialize` or `Debug` on a struct holding one doesn't leak it to logs or debug dumps.
+/// The real bytes are only serialized and debug printed through
+/// [`Seed::expose()`][Seed::expose()], and that is what deserializing reads back.
+///
 /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
-/// [Seed]: ./seed/struct.Seed.html
-/// [Seed::as_bytes()]: ./seed/struct.Seed.html#method.as_bytes
+/// [Seed]: ./seed/type.Seed.html
+/// [Seed::as_bytes()]: ./seed/struct.GenericSeed.html#method.as_bytes
+/// [Seed::is_locked()]: ./seed/struct.GenericSeed.html#method.is_locked
+/// [Seed::expose()]: ./seed/struct.GenericSeed.html#method.expose
+#[derive(Clone, Deserialize)]
+pub struct GenericSeed<const N: usize> {
+  #[serde(deserialize_with = "serde_seed::deserialize")]
+  bytes: SeedBytes<N>,
+  #[serde(skip)]
+  has_password: bool,
+}
+
+/// Storage of the seed bytes, in locked memory with the `mlock` feature
+#[cfg(feature = "mlock")]
+type SeedBytes<const N: usize> = crate::locked::LockedBytes<N>;
+#[cfg(not(feature = "mlock"))]
+type SeedBytes<const N: usize> = [u8; N];
+
+/// Move `array` into seed storage, wiping it
+fn seed_bytes<const N: usize>(array: &mut [u8; N]) -> SeedBytes<N> {
+    #[cfg(feature = "mlock")]
+    let bytes = crate::locked::LockedBytes::new(array);
+    #[cfg(not(feature = "mlock"))]
+    let bytes = *array;
 
-#[derive(Clone, Serialize, Deserialize)]
-pub struct Seed {
-  #[serde(with = "serde_seed")]
-  bytes: Vec<u8>,
+    array.zeroize();
+    bytes
 }
 
-impl Seed {
+/// A standard BIP39 seed of 64 bytes, see [`GenericSeed`][GenericSeed]
+///
+/// [GenericSeed]: ./seed/struct.GenericSeed.html
+pub type Seed = GenericSeed<64>;
+
+impl<const N: usize> GenericSeed<N> {
     /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password.
     ///
+    /// For a [`Seed`][Seed] this is the standard BIP39 derivation. Other lengths use the same
+    /// PBKDF2 derivation with an output of `N` bytes, so a `GenericSeed<32>` is the first half
+    /// of the corresponding [`Seed`][Seed].
+    ///
+    /// The [`Mnemonic`][Mnemonic] is already validated, so when the password changes the new
+    /// seed is derived by calling this again with the same mnemonic, without re-parsing the
+    /// phrase.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::EmptyPhrase` if the phrase is empty, which a
+    /// validated [`Mnemonic`][Mnemonic] never is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{GenericSeed, Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = Seed::new(&mnemonic, "password").unwrap();
+    /// let short = GenericSeed::<32>::new(&mnemonic, "password").unwrap();
+    ///
+    /// assert_eq!(short.as_bytes(), &seed.as_bytes()[..32]);
+    /// ```
+    ///
     /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
-    pub fn new(mnemonic: &Mnemonic, password: &str) -> Self {
+    /// [Seed]: ./seed/type.Seed.html
+    pub fn new(mnemonic: &Mnemonic, password: &str) -> Result<Self, Error> {
         let salt = format!("mnemonic{}", password);
         let normalized_salt = salt.nfkd().to_string();
-        let bytes = pbkdf2(mnemonic.phrase().as_bytes(), &normalized_salt);
 
-        Self { bytes }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            language = ?mnemonic.language(),
+            word_count = mnemonic.words().count(),
+            password_normalization_applied = normalized_salt != salt,
+            iterations = PBKDF2_ROUNDS,
+            "deriving seed"
+        );
+
+        let mut bytes = seed_bytes(&mut [0u8; N]);
+        pbkdf2_into(mnemonic.phrase().as_bytes(), &normalized_salt, &mut bytes[..])?;
+
+        Ok(Self {
+            bytes,
+            has_password: !password.is_empty(),
+        })
+    }
+
+    /// Create a seed from bytes obtained elsewhere, e.g. loaded from storage
+    ///
+    /// Whether a password was used is unknown, so
+    /// [`has_password()`][GenericSeed::has_password()] returns `false`.
+    ///
+    /// [GenericSeed::has_password()]: ./seed/struct.GenericSeed.html#method.has_password
+    pub fn from_array(mut bytes: [u8; N]) -> Self {
+        Self {
+            bytes: seed_bytes(&mut bytes),
+            has_password: false,
+        }
+    }
+
+    /// Create a seed from its hex encoding, as formatted with `{:x}`, `{:X}`, `{:#x}` or `{:#X}`
+    ///
+    /// Digits are case insensitive, even mixed within the string, and an optional `0x` or `0X`
+    /// prefix is ignored. Returns an `Error` of kind `ErrorKind::InvalidHex` if the string isn't
+    /// valid hex, and `ErrorKind::InvalidSeedLength` if it doesn't encode exactly `N` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// let parsed = Seed::from_hex(&format!("{:#X}", seed)).unwrap();
+    ///
+    /// assert_eq!(parsed.as_bytes(), seed.as_bytes());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let mut decoded = from_hex(hex)?;
+
+        if decoded.len() != N {
+            let len = decoded.len();
+            decoded.zeroize();
+            Err(ErrorKind::InvalidSeedLength(len))?;
+        }
+
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&decoded);
+        decoded.zeroize();
+
+        Ok(Self::from_array(bytes))
+    }
+
+    /// Whether a non-empty password was used to derive this seed, e.g. to show a "passphrase
+    /// protected" badge
+    ///
+    /// Only this flag is kept, never the password itself. It isn't serialized either, so seeds
+    /// that are deserialized, or created from raw bytes, always return `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert!(Seed::new(&mnemonic, "password").unwrap().has_password());
+    /// assert!(!Seed::new(&mnemonic, "").unwrap().has_password());
+    /// ```
+    pub fn has_password(&self) -> bool {
+        self.has_password
+    }
+
+    /// Whether the seed bytes are in locked memory, safe from being paged to disk
+    ///
+    /// Always `false` without the `mlock` feature. With it, every seed is locked with `mlock(2)`
+    /// on Unix platforms, in pages of its own. Locking fails gracefully when the process exceeds
+    /// its `RLIMIT_MEMLOCK` limit (see `ulimit -l`) or on other platforms: the seed then lives in
+    /// ordinary memory and this returns `false`. Either way the bytes are wiped on drop.
+    pub fn is_locked(&self) -> bool {
+        #[cfg(feature = "mlock")]
+        return self.bytes.is_locked();
+        #[cfg(not(feature = "mlock"))]
+        return false;
     }
 
     /// Get the seed value as a byte slice
     pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+
+    /// Get the length of the seed in bytes, always `N` (64 for a [`Seed`][Seed])
+    ///
+    /// [Seed]: ./type.Seed.html
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Whether the seed has no bytes, which is only the case for a `GenericSeed<0>`
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Get the seed value as a fixed size array, whose length is known at compile time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let bytes: &[u8; 64] = Seed::new(&mnemonic, "").unwrap().as_array();
+    /// ```
+    pub fn as_array(&self) -> &[u8; N] {
         &self.bytes
     }
+
+    /// Convert the seed into a fixed size array, or get the seed back if its length isn't `M`
+    ///
+    /// A [`Seed`][Seed] derived from a [`Mnemonic`][Mnemonic] is always 64 bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    /// let seed = seed.try_into_array::<32>().unwrap_err();
+    /// let bytes: [u8; 64] = seed.try_into_array().unwrap();
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed]: ./seed/type.Seed.html
+    pub fn try_into_array<const M: usize>(self) -> Result<[u8; M], Self> {
+        if N != M {
+            return Err(self);
+        }
+
+        let mut array = [0u8; M];
+        array.copy_from_slice(&self.bytes[..]);
+
+        Ok(array)
+    }
+}
+
+impl Seed {
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password like
+    /// [`Seed::new()`][Seed::new()], but on the blocking thread pool of the tokio runtime, so
+    /// that the CPU bound derivation doesn't stall other tasks.
+    ///
+    /// Requires the `tokio` feature, and must be awaited from within a tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = Seed::new_async(&mnemonic, "password").await.unwrap();
+    ///
+    /// assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+    /// # }
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    #[cfg(feature = "tokio")]
+    pub fn new_async(
+        mnemonic: &Mnemonic,
+        password: &str,
+    ) -> impl std::future::Future<Output = Result<Seed, Error>> {
+        let mnemonic = mnemonic.clone();
+        let password = zeroize::Zeroizing::new(password.to_owned());
+
+        async move {
+            tokio::task::spawn_blocking(move || Seed::new(&mnemonic, &password))
+                .await
+                .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))
+        }
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password **without** NFKD
+    /// normalizing the password.
+    ///
+    /// This is not BIP39 compliant: some historical wallets failed to normalize the password,
+    /// producing different seeds for non-ASCII passwords. Only use this to recover funds from
+    /// such wallets, and [`Seed::new()`][Seed::new()] otherwise. For ASCII passwords both produce
+    /// the same seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let legacy = Seed::new_legacy_no_normalize(&mnemonic, "password").unwrap();
+    ///
+    /// assert_eq!(legacy.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    pub fn new_legacy_no_normalize(mnemonic: &Mnemonic, password: &str) -> Result<Self, Error> {
+        let salt = format!("mnemonic{}", password);
+        Ok(Seed::from_vec(
+            pbkdf2(mnemonic.phrase().as_bytes(), &salt)?,
+            !password.is_empty(),
+        ))
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password using HMAC-SHA256
+    /// instead of HMAC-SHA512 in PBKDF2.
+    ///
+    /// This is **not** BIP39 compliant and produces a different seed than
+    /// [`Seed::new()`][Seed::new()]. Some forks derive their seeds this way, only use this to
+    /// recover funds from such wallets. The password is normalized as in
+    /// [`Seed::new()`][Seed::new()] and the seed is still 64 bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = Seed::new_sha256(&mnemonic, "password").unwrap();
+    ///
+    /// assert_ne!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    pub fn new_sha256(mnemonic: &Mnemonic, password: &str) -> Result<Self, Error> {
+        let salt = format!("mnemonic{}", password).nfkd().to_string();
+        let bytes = pbkdf2_with(
+            mnemonic.phrase().as_bytes(),
+            &salt,
+            PBKDF2_ROUNDS,
+            Pbkdf2Hash::Sha256,
+        )?;
+
+        Ok(Seed::from_vec(bytes, !password.is_empty()))
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password like
+    /// [`Seed::new()`][Seed::new()], reporting the progress of the derivation in percent to
+    /// `progress`.
+    ///
+    /// The standard PBKDF2 derivation is fast, so it only reports 0 before starting and 100 once
+    /// done, but this lets UIs stay responsive should costlier derivations be supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let mut reports = Vec::new();
+    /// let seed = Seed::new_with_progress(&mnemonic, "", |percent| reports.push(percent)).unwrap();
+    ///
+    /// assert_eq!(reports, [0, 100]);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    pub fn new_with_progress<F>(
+        mnemonic: &Mnemonic,
+        password: &str,
+        mut progress: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(u8),
+    {
+        progress(0);
+
+        let seed = Seed::new(mnemonic, password)?;
+
+        progress(100);
+
+        Ok(seed)
+    }
+
+    /// Generates the seed from the [`Mnemonic`][Mnemonic] and the password, returning it together
+    /// with a copy of the mnemonic's entropy.
+    ///
+    /// Useful for devices that display the first bytes of the seed and want to re-confirm the
+    /// entropy matches what the user intended in a single call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let (seed, entropy) = Seed::derive(&mnemonic, "password").unwrap();
+    ///
+    /// assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+    /// assert_eq!(entropy, mnemonic.entropy());
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn derive(mnemonic: &Mnemonic, password: &str) -> Result<(Seed, Vec<u8>), Error> {
+        Ok((Seed::new(mnemonic, password)?, mnemonic.entropy().to_vec()))
+    }
+
+    /// Derive only the first `len` bytes of the seed for the [`Mnemonic`][Mnemonic] and the
+    /// password, e.g. for a short fingerprint.
+    ///
+    /// PBKDF2 only computes the blocks needed to cover `len` bytes. Note that a PBKDF2-HMAC-SHA512
+    /// block is 64 bytes, the size of a whole BIP39 seed, so this costs as much as
+    /// [`Seed::new()`][Seed::new()]; it only avoids keeping the rest of the seed around.
+    ///
+    /// The prefix is as secret as the seed, so it is wiped from memory when dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is larger than the 64 bytes of a seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let prefix = Seed::derive_prefix(&mnemonic, "", 4).unwrap();
+    ///
+    /// assert_eq!(&prefix[..], &Seed::new(&mnemonic, "").unwrap().as_bytes()[..4]);
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    pub fn derive_prefix(
+        mnemonic: &Mnemonic,
+        password: &str,
+        len: usize,
+    ) -> Result<zeroize::Zeroizing<Vec<u8>>, Error> {
+        assert!(len <= 64, "a seed is only 64 bytes long");
+
+        let salt = zeroize::Zeroizing::new(format!("mnemonic{}", password).nfkd().to_string());
+
+        let mut bytes = zeroize::Zeroizing::new(vec![0u8; len]);
+        pbkdf2_into(mnemonic.phrase().as_bytes(), &salt, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Generates one seed per password from the same [`Mnemonic`][Mnemonic].
+    ///
+    /// This is equivalent to calling [`Seed::new()`][Seed::new()] for each password, but the
+    /// key setup for the mnemonic is only done once. Useful for managing several passphrase
+    /// protected ("hidden") wallets derived from the same phrase.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seeds = Seed::for_passwords(&mnemonic, &["", "hidden"]).unwrap();
+    ///
+    /// assert_eq!(seeds[1].as_bytes(), Seed::new(&mnemonic, "hidden").unwrap().as_bytes());
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    pub fn for_passwords(mnemonic: &Mnemonic, passwords: &[&str]) -> Result<Vec<Seed>, Error> {
+        let salts: Vec<String> = passwords
+            .iter()
+            .map(|password| format!("mnemonic{}", password).nfkd().to_string())
+            .collect();
+
+        let seeds = pbkdf2_many(mnemonic.phrase().as_bytes(), &salts)?
+            .into_iter()
+            .zip(passwords)
+            .map(|(bytes, password)| Seed::from_vec(bytes, !password.is_empty()))
+            .collect();
+
+        Ok(seeds)
+    }
+
+    /// Generates the seed for every numeric PIN from `start` up to, but excluding, `end`, used as
+    /// the password
+    ///
+    /// Yields `(pin, seed)` pairs in order, each seed equal to that of
+    /// [`Seed::new()`][Seed::new()] with the PIN in decimal as the password. Like
+    /// [`Seed::for_passwords()`][Seed::for_passwords()], the key setup for the mnemonic is only
+    /// done once, and seeds are only derived as the iterator is advanced, so large ranges can
+    /// be searched without holding every seed. PINs are formatted without leading zeros: use
+    /// [`Seed::for_passwords()`][Seed::for_passwords()] for zero padded ones such as `"0042"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let (pin, seed) = Seed::for_pin_range(&mnemonic, 1000, 10000).unwrap().nth(234).unwrap();
+    ///
+    /// assert_eq!(pin, 1234);
+    /// assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "1234").unwrap().as_bytes());
+    /// ```
+    ///
+    /// [Seed::new()]: ./seed/type.Seed.html#method.new
+    /// [Seed::for_passwords()]: ./seed/type.Seed.html#method.for_passwords
+    pub fn for_pin_range(
+        mnemonic: &Mnemonic,
+        start: u64,
+        end: u64,
+    ) -> Result<impl Iterator<Item = (u64, Seed)>, Error> {
+        let prf = pbkdf2_prf(mnemonic.phrase().as_bytes())?;
+
+        Ok((start..end).map(move |pin| {
+            let salt = format!("mnemonic{}", pin);
+
+            let mut bytes = [0u8; 64];
+            pbkdf2_body(&prf, salt.as_bytes(), PBKDF2_ROUNDS, &mut bytes);
+
+            let seed = Self {
+                bytes: seed_bytes(&mut bytes),
+                has_password: true,
+            };
+
+            (pin, seed)
+        }))
+    }
+
+    /// Take ownership of PBKDF2 output of the standard length, wiping the vector
+    fn from_vec(mut bytes: Vec<u8>, has_password: bool) -> Self {
+        let mut array = [0u8; 64];
+        array.copy_from_slice(&bytes);
+        bytes.zeroize();
+
+        Self {
+            bytes: seed_bytes(&mut array),
+            has_password,
+        }
+    }
 }
 
-impl AsRef<[u8]> for Seed {
+impl<const N: usize> GenericSeed<N> {
+    /// Serialize or debug print the real seed bytes, instead of the `"<redacted>"` placeholder
+    ///
+    /// Human readable formats (e.g. JSON) get the bytes as a hex string, binary formats get them
+    /// prefixed with a format version byte. `Debug` prints them in uppercase hex. The output is read back by deserializing a
+    /// [`Seed`][Seed]. Only use this where the seed is meant to be stored, as anyone reading the
+    /// output can use the seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// assert_eq!(serde_json::to_string(&seed).unwrap(), r#""<redacted>""#);
+    ///
+    /// let json = serde_json::to_string(&seed.expose()).unwrap();
+    /// let restored: Seed = serde_json::from_str(&json).unwrap();
+    ///
+    /// assert_eq!(restored.as_bytes(), seed.as_bytes());
+    /// ```
+    ///
+    /// [Seed]: ./seed/type.Seed.html
+    pub fn expose(&self) -> Exposed<'_, N> {
+        Exposed(self)
+    }
+
+    /// Derive an independent sub-seed for a specific purpose, identified by `domain`
+    ///
+    /// The sub-seed is HMAC-SHA512 keyed with this seed over the domain, so different domains
+    /// give unrelated sub-seeds, and a sub-seed doesn't reveal the seed it came from. This is a
+    /// simple domain separated KDF for application specific keys, **not** BIP32 key derivation,
+    /// which is outside the scope of this crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// let signing = seed.derive_subseed(b"example.com/signing");
+    /// let encryption = seed.derive_subseed(b"example.com/encryption");
+    ///
+    /// assert_ne!(signing.as_bytes(), encryption.as_bytes());
+    /// ```
+    pub fn derive_subseed(&self, domain: &[u8]) -> Seed {
+        Seed::from_vec(hmac_sha512(&self.bytes[..], domain), self.has_password)
+    }
+
+    /// Compare with another [`Seed`][Seed] in constant time
+    ///
+    /// [Seed]: ./seed/type.Seed.html
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.ct_eq_bytes(&other.bytes[..])
+    }
+
+    /// Compare the seed with raw bytes, e.g. a seed loaded from storage, in constant time
+    ///
+    /// Returns `false` if the lengths differ. The lengths themselves aren't secret, so only the
+    /// comparison of the contents is constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    /// let stored: Vec<u8> = seed.as_bytes().to_vec();
+    ///
+    /// assert!(seed.ct_eq_bytes(&stored));
+    /// assert!(!seed.ct_eq_bytes(&stored[..32]));
+    /// ```
+    pub fn ct_eq_bytes(&self, other: &[u8]) -> bool {
+        self.bytes.as_slice().ct_eq(other).into()
+    }
+
+    /// Pass the lowercase hex encoding of the seed to `f`, wiping it as soon as `f` returns
+    ///
+    /// The hex string only lives for the duration of the call, unlike a `String` from
+    /// `format!("{:x}", seed)` that the caller has to remember to wipe. It is written into a
+    /// buffer allocated once at its final size, so no partial copies are left behind by
+    /// reallocations either.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// // e.g. render it for the user, without keeping a copy around
+    /// seed.with_hex(|hex| assert_eq!(hex.len(), 128));
+    /// ```
+    pub fn with_hex<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        use std::fmt::Write;
+
+        let mut hex = zeroize::Zeroizing::new(String::with_capacity(N * 2));
+        write!(hex, "{:x}", self).expect("writing to a String can't fail");
+
+        f(&hex)
+    }
+
+    /// Split the lowercase hex encoding of the seed into pieces of `chunk_len` hex digits, for
+    /// backups stored in separate places. The last piece is shorter if `chunk_len` doesn't
+    /// divide the length of the hex string.
+    ///
+    /// Use [`Seed::from_hex_chunks()`][Seed::from_hex_chunks()] to reassemble the seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, Seed};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let seed = Seed::new(&mnemonic, "").unwrap();
+    ///
+    /// let chunks = seed.to_hex_chunks(32);
+    ///
+    /// assert_eq!(chunks.len(), 4);
+    /// assert_eq!(Seed::from_hex_chunks(&chunks).unwrap().as_bytes(), seed.as_bytes());
+    /// ```
+    ///
+    /// [Seed::from_hex_chunks()]: ./seed/type.Seed.html#method.from_hex_chunks
+    pub fn to_hex_chunks(&self, chunk_len: usize) -> Vec<String> {
+        assert!(chunk_len != 0, "chunk_len must be greater than 0");
+
+        let mut hex = format!("{:x}", self);
+
+        let chunks = hex
+            .as_bytes()
+            .chunks(chunk_len)
+            .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+            .collect();
+
+        hex.zeroize();
+
+        chunks
+    }
+
+    /// Reassemble a seed from hex pieces created by
+    /// [`Seed::to_hex_chunks()`][Seed::to_hex_chunks()]
+    ///
+    /// Surrounding whitespace of each piece is ignored. Returns an `Error` of kind
+    /// `ErrorKind::InvalidHex` if the pieces aren't valid hex once joined, and
+    /// `ErrorKind::InvalidSeedLength` if they don't add up to a full seed, e.g. because a piece
+    /// is missing.
+    ///
+    /// [Seed::to_hex_chunks()]: ./seed/type.Seed.html#method.to_hex_chunks
+    pub fn from_hex_chunks(chunks: &[String]) -> Result<Self, Error> {
+        let mut hex: String = chunks.iter().map(|chunk| chunk.trim()).collect();
+
+        let seed = Self::from_hex(&hex);
+        hex.zeroize();
+
+        seed
+    }
+}
+
+/// Builder for deriving a [`Seed`][Seed] with non-standard parameters.
+///
+/// The defaults exactly reproduce [`Seed::new()`][Seed::new()]: both the phrase and the password
+/// are NFKD normalized, words are separated by a single space, and PBKDF2 runs for 2048 rounds.
+/// Changing any of these produces seeds that are **not** BIP39 compliant, and is only meant for
+/// interoperability with wallets that deviate from the standard.
+///
+/// # Example
+///
+/// ```
+/// use bip39::{Mnemonic, Language, Seed, SeedBuilder};
+///
+/// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+/// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+///
+/// let seed = SeedBuilder::new(&mnemonic).password("password").derive();
+///
+/// assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+/// ```
+///
+/// [Seed]: ./seed/type.Seed.html
+/// [Seed::new()]: ./seed/type.Seed.html#method.new
+#[derive(Clone)]
+pub struct SeedBuilder<'a> {
+    mnemonic: &'a Mnemonic,
+    password: &'a str,
+    normalization: NormalizationForm,
+    separator: &'a str,
+    iterations: usize,
+    max_password_len: Option<usize>,
+}
+
+impl<'a> SeedBuilder<'a> {
+    /// Create a builder for the [`Mnemonic`][Mnemonic] with the standard BIP39 parameters.
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn new(mnemonic: &'a Mnemonic) -> Self {
+        SeedBuilder {
+            mnemonic,
+            password: "",
+            normalization: NormalizationForm::Nfkd,
+            separator: " ",
+            iterations: PBKDF2_ROUNDS,
+            max_password_len: None,
+        }
+    }
+
+    /// Set the password, empty by default
+    pub fn password(mut self, password: &'a str) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// Toggle NFKD normalization of the phrase and password, enabled by default
+    ///
+    /// Disabling it is the same as setting
+    /// [`NormalizationForm::None`][NormalizationForm::None] with
+    /// [`SeedBuilder::normalization_form()`][SeedBuilder::normalization_form()].
+    ///
+    /// [NormalizationForm::None]: ./seed/enum.NormalizationForm.html#variant.None
+    /// [SeedBuilder::normalization_form()]: ./seed/struct.SeedBuilder.html#method.normalization_form
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalization = if normalize {
+            NormalizationForm::Nfkd
+        } else {
+            NormalizationForm::None
+        };
+        self
+    }
+
+    /// Set the Unicode normalization form applied to the phrase and password, NFKD by default
+    ///
+    /// BIP39 mandates NFKD. The other forms are **not** standard, and only meant for recovering
+    /// the seeds of the few wallets known to have normalized with NFC, or not at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, NormalizationForm, SeedBuilder};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// // NFC keeps the "ﬁ" ligature that NFKD turns into "fi"
+    /// let nfc = SeedBuilder::new(&mnemonic).password("ﬁat").normalization_form(NormalizationForm::Nfc).derive();
+    /// let nfkd = SeedBuilder::new(&mnemonic).password("ﬁat").derive();
+    ///
+    /// assert_ne!(nfc.as_bytes(), nfkd.as_bytes());
+    /// ```
+    pub fn normalization_form(mut self, form: NormalizationForm) -> Self {
+        self.normalization = form;
+        self
+    }
+
+    /// Set the separator placed between the words of the phrase, a single space by default
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the number of PBKDF2 iterations, 2048 by default
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Limit the length of the password to `max_len` bytes of UTF-8, unlimited by default
+    ///
+    /// The password is copied into the salt and normalized before deriving, so a service
+    /// deriving seeds from passwords it receives should limit their length, or clients can make
+    /// it allocate and normalize arbitrarily large inputs. The limit applies
+    /// to the password as given, before normalization, and is enforced by
+    /// [`SeedBuilder::try_derive()`][SeedBuilder::try_derive()]. This is not part of BIP39, and
+    /// doesn't change the seeds of the passwords within the limit.
+    ///
+    /// [SeedBuilder::try_derive()]: ./seed/struct.SeedBuilder.html#method.try_derive
+    pub fn max_password_len(mut self, max_len: usize) -> Self {
+        self.max_password_len = Some(max_len);
+        self
+    }
+
+    /// Derive the [`Seed`][Seed] with the configured parameters, checking the length of the
+    /// password
+    ///
+    /// Returns an `Error` of kind `ErrorKind::PasswordTooLong` if the password exceeds the
+    /// [`SeedBuilder::max_password_len()`][SeedBuilder::max_password_len()], without doing any
+    /// of the work of deriving the seed. Always succeeds if no limit was set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, SeedBuilder};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    /// let password = "a".repeat(2000);
+    ///
+    /// let builder = SeedBuilder::new(&mnemonic).password(&password).max_password_len(1024);
+    ///
+    /// assert!(builder.try_derive().is_err());
+    /// ```
+    ///
+    /// [Seed]: ./seed/type.Seed.html
+    /// [SeedBuilder::max_password_len()]: ./seed/struct.SeedBuilder.html#method.max_password_len
+    pub fn try_derive(&self) -> Result<Seed, Error> {
+        if let Some(max) = self.max_password_len {
+            if self.password.len() > max {
+                Err(ErrorKind::PasswordTooLong {
+                    len: self.password.len(),
+                    max,
+                })?;
+            }
+        }
+
+        self.derive_unchecked()
+    }
+
+    /// Derive the [`Seed`][Seed] with the configured parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the password exceeds the
+    /// [`SeedBuilder::max_password_len()`][SeedBuilder::max_password_len()], use
+    /// [`SeedBuilder::try_derive()`][SeedBuilder::try_derive()] when setting a limit.
+    ///
+    /// [Seed]: ./seed/type.Seed.html
+    /// [SeedBuilder::max_password_len()]: ./seed/struct.SeedBuilder.html#method.max_password_len
+    /// [SeedBuilder::try_derive()]: ./seed/struct.SeedBuilder.html#method.try_derive
+    pub fn derive(&self) -> Seed {
+        match self.try_derive() {
+            Ok(seed) => seed,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Describe how this builder derives seeds, without the phrase, password or seed
+    ///
+    /// Useful for bug reports and test vectors, where stating the exact parameters matters but
+    /// the secrets must not leak.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language, SeedBuilder};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let params = SeedBuilder::new(&mnemonic).password("password").derivation_params();
+    ///
+    /// assert_eq!(
+    ///     params.to_string(),
+    ///     "English, 12 words, PBKDF2-HMAC-SHA512 with 2048 iterations, NFKD normalized, words separated by \" \""
+    /// );
+    /// ```
+    pub fn derivation_params(&self) -> DerivationParams {
+        DerivationParams {
+            language: self.mnemonic.language(),
+            word_count: self.mnemonic.words().count(),
+            iterations: self.iterations,
+            hash: "SHA512",
+            normalization: self.normalization,
+            separator: self.separator.to_owned(),
+        }
+    }
+
+    fn derive_unchecked(&self) -> Result<Seed, Error> {
+        let phrase = self.mnemonic.phrase().split(' ').collect::<Vec<_>>().join(self.separator);
+        let salt = format!("mnemonic{}", self.password);
+
+        let (phrase, salt) = match self.normalization {
+            NormalizationForm::Nfkd => (phrase.nfkd().to_string(), salt.nfkd().to_string()),
+            NormalizationForm::Nfc => (phrase.nfc().to_string(), salt.nfc().to_string()),
+            NormalizationForm::None => (phrase, salt),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            language = ?self.mnemonic.language(),
+            word_count = self.mnemonic.words().count(),
+            normalization = ?self.normalization,
+            custom_separator = self.separator != " ",
+            iterations = self.iterations,
+            "deriving seed with builder"
+        );
+
+        Ok(Seed::from_vec(
+            pbkdf2_rounds(phrase.as_bytes(), &salt, self.iterations)?,
+            !self.password.is_empty(),
+        ))
+    }
+}
+
+/// The Unicode normalization form a [`SeedBuilder`][SeedBuilder] applies to the phrase and
+/// password, see [`SeedBuilder::normalization_form()`][SeedBuilder::normalization_form()]
+///
+/// Only [`NormalizationForm::Nfkd`][NormalizationForm::Nfkd] is standard BIP39.
+///
+/// [SeedBuilder]: ./seed/struct.SeedBuilder.html
+/// [SeedBuilder::normalization_form()]: ./seed/struct.SeedBuilder.html#method.normalization_form
+/// [NormalizationForm::Nfkd]: ./seed/enum.NormalizationForm.html#variant.Nfkd
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NormalizationForm {
+    /// Compatibility decomposition, as BIP39 mandates
+    #[default]
+    Nfkd,
+    /// Canonical composition, used by some misbehaving wallets
+    Nfc,
+    /// No normalization at all
+    None,
+}
+
+/// The parameters a [`SeedBuilder`][SeedBuilder] derives seeds with, see
+/// [`SeedBuilder::derivation_params()`][SeedBuilder::derivation_params()]
+///
+/// The `Display` implementation gives a one line summary for bug reports.
+///
+/// [SeedBuilder]: ./seed/struct.SeedBuilder.html
+/// [SeedBuilder::derivation_params()]: ./seed/struct.SeedBuilder.html#method.derivation_params
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationParams {
+    /// Language of the phrase
+    pub language: Language,
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// Number of PBKDF2 iterations, 2048 in BIP39
+    pub iterations: usize,
+    /// Hash function of the HMAC used by PBKDF2, SHA512 in BIP39
+    pub hash: &'static str,
+    /// Normalization form of the phrase and password, NFKD in BIP39
+    pub normalization: NormalizationForm,
+    /// Separator placed between the words of the phrase, a single space in BIP39
+    pub separator: String,
+}
+
+impl DerivationParams {
+    /// Whether these are the parameters of standard BIP39 seeds
+    pub fn is_standard(&self) -> bool {
+        self.iterations == PBKDF2_ROUNDS
+            && self.hash == "SHA512"
+            && self.normalization == NormalizationForm::Nfkd
+            && self.separator == " "
+    }
+}
+
+impl fmt::Display for DerivationParams {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, {} words, PBKDF2-HMAC-{} with {} iterations, {}, words separated by {:?}",
+            self.language.name(),
+            self.word_count,
+            self.hash,
+            self.iterations,
+            match self.normalization {
+                NormalizationForm::Nfkd => "NFKD normalized",
+                NormalizationForm::Nfc => "NFC normalized",
+                NormalizationForm::None => "not normalized",
+            },
+            self.separator
+        )
+    }
+}
+
+impl<const N: usize> Drop for GenericSeed<N> {
+    fn drop(&mut self) {
+        self.bytes[..].zeroize();
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for GenericSeed<N> {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
 
-impl fmt::Debug for Seed {
+/// Copy seed bytes obtained elsewhere into a seed, failing with an `Error` of kind
+/// `ErrorKind::InvalidSeedLength` unless there are exactly `N` of them (64 for a [`Seed`][Seed])
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use bip39::Seed;
+///
+/// assert!(Seed::try_from(&[0u8; 64][..]).is_ok());
+/// assert!(Seed::try_from(&[0u8; 32][..]).is_err());
+/// ```
+///
+/// [Seed]: ./seed/type.Seed.html
+impl<const N: usize> TryFrom<&[u8]> for GenericSeed<N> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != N {
+            Err(ErrorKind::InvalidSeedLength(bytes.len()))?;
+        }
+
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+
+        Ok(Self::from_array(array))
+    }
+}
+
+impl<const N: usize> fmt::Debug for GenericSeed<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:#X}", self)
+        f.debug_struct("GenericSeed")
+            .field("bytes", &format_args!("<redacted>"))
+            .field("has_password", &self.has_password)
+            .finish()
     }
 }
 
-impl fmt::LowerHex for Seed {
+impl<const N: usize> fmt::LowerHex for GenericSeed<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if f.alternate() {
             f.write_str("0x")?;
         }
 
-        for byte in &self.bytes {
+        for byte in self.bytes.iter() {
             write!(f, "{:02x}", byte)?;
         }
 
@@ -67,73 +1122,166 @@ impl fmt::LowerHex for Seed {
     }
 }
 
-impl fmt::UpperHex for Seed {
+impl<const N: usize> fmt::UpperHex for GenericSeed<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if f.alternate() {
             f.write_str("0x")?;
         }
 
-        for byte in &self.bytes {
-            write!(f, "{:02X}", byte)?;
-        }
+        for byte in self.bytes.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> serde::Serialize for GenericSeed<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("<redacted>")
+    }
+}
+
+/// A seed that serializes its real bytes, returned by [`Seed::expose()`][Seed::expose()]
+///
+/// [Seed::expose()]: ./seed/struct.GenericSeed.html#method.expose
+#[derive(Clone, Copy)]
+pub struct Exposed<'a, const N: usize>(&'a GenericSeed<N>);
+
+impl<const N: usize> fmt::Debug for Exposed<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#X}", self.0)
+    }
+}
+
+impl<const N: usize> serde::Serialize for Exposed<'_, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
 
-        Ok(())
+        let mut state = serializer.serialize_struct("GenericSeed", 1)?;
+        state.serialize_field("bytes", &ExposedBytes(&self.0.bytes))?;
+        state.end()
+    }
+}
+
+/// The seed bytes, serialized by the custom serializer below
+struct ExposedBytes<'a, const N: usize>(&'a SeedBytes<N>);
+
+impl<const N: usize> serde::Serialize for ExposedBytes<'_, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_seed::serialize(self.0, serializer)
     }
 }
 
 /// Custom serializer for Seed
+///
+/// Human readable formats (e.g. JSON) get a plain hex string. Binary formats get a byte string
+/// prefixed with a format version byte, so that future format changes can be detected. In CBOR
+/// (e.g. with `ciborium`) this is a definite-length byte string of major type 2, as COSE and
+/// CTAP expect.
 mod serde_seed {
+	use serde::de::{self, SeqAccess, Visitor};
 	use serde::{Deserialize, Deserializer, Serializer};
-	use crate::Seed;
-	use std::num;
+	use crate::error::ErrorKind;
+	use crate::util::from_hex;
+	use crate::GenericSeed;
+	use super::{seed_bytes, SeedBytes};
+	use std::fmt;
+	use zeroize::{Zeroize, Zeroizing};
 
-	///
-	pub fn serialize<S>(seed: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error>
+	/// Version byte prepended to the seed bytes in binary formats
+	pub const FORMAT_VERSION: u8 = 1;
+
+	/// Serialize the seed bytes as a lowercase hex string, or versioned bytes for binary formats
+	pub fn serialize<S, const N: usize>(seed: &SeedBytes<N>, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
-		serializer.serialize_str(&format!("{:x}", Seed{bytes: seed.clone()}))
+		if serializer.is_human_readable() {
+			let mut array = [0u8; N];
+			array.copy_from_slice(&seed[..]);
+			let hex = Zeroizing::new(format!("{:x}", GenericSeed::from_array(array)));
+			serializer.serialize_str(&hex)
+		} else {
+			let mut bytes = Zeroizing::new(Vec::with_capacity(seed.len() + 1));
+			bytes.push(FORMAT_VERSION);
+			bytes.extend_from_slice(&seed[..]);
+			serializer.serialize_bytes(&bytes)
+		}
 	}
 
-	///
-	pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+	/// Deserialize the seed bytes from a hex string in any case, with or without a `0x` or `0X`
+	/// prefix, or from versioned bytes for binary formats, checking that there are `N` of them
+	pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<SeedBytes<N>, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
 		use serde::de::Error;
-		String::deserialize(deserializer)
-			.and_then(|string| from_hex(string).map_err(|err| Error::custom(err.to_string())))
-			.and_then(|bytes: Vec<u8>| {
-				Ok(bytes)
-			})
-	}
-
-	/// Decode a hex string into bytes.
-	fn from_hex(hex_str: String) -> Result<Vec<u8>, num::ParseIntError> {
-		if hex_str.len() % 2 == 1 {
-			// TODO: other way to instantiate a ParseIntError?
-			let err = ("QQQ").parse::<u64>();
-			if let Err(e) = err {
-				return Err(e);
-			}
-		}
-		let hex_trim = if &hex_str[..2] == "0x" {
-			hex_str[2..].to_owned()
+		let mut bytes = if deserializer.is_human_readable() {
+			String::deserialize(deserializer)
+				.and_then(|string| from_hex(string.trim()).map_err(Error::custom))?
 		} else {
-			hex_str.clone()
+			let mut bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+			let seed = match bytes.split_first() {
+				Some((&FORMAT_VERSION, seed)) => Ok(seed.to_vec()),
+				Some((version, _)) => {
+					Err(Error::custom(format!("unsupported seed format version {}", version)))
+				}
+				None => Err(Error::custom("missing seed format version")),
+			};
+			bytes.zeroize();
+			seed?
 		};
-		split_n(&hex_trim.trim()[..], 2)
-			.iter()
-			.map(|b| u8::from_str_radix(b, 16))
-			.collect::<Result<Vec<u8>, _>>()
+
+		if bytes.len() != N {
+			let len = bytes.len();
+			bytes.zeroize();
+			return Err(Error::custom(ErrorKind::InvalidSeedLength(len)));
+		}
+
+		let mut seed = [0u8; N];
+		seed.copy_from_slice(&bytes);
+		bytes.zeroize();
+
+		Ok(seed_bytes(&mut seed))
 	}
 
-	fn split_n(s: &str, n: usize) -> Vec<&str> {
-		(0..(s.len() - n + 1) / 2 + 1)
-			.map(|i| &s[2 * i..2 * i + n])
-			.collect()
+	struct BytesVisitor;
+
+	impl<'de> Visitor<'de> for BytesVisitor {
+		type Value = Vec<u8>;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("versioned seed bytes")
+		}
+
+		fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+			Ok(bytes.to_vec())
+		}
+
+		fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+			Ok(bytes)
+		}
+
+		fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+			let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+			while let Some(byte) = seq.next_element()? {
+				bytes.push(byte);
+			}
+			Ok(bytes)
+		}
 	}
 }
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -147,7 +1295,7 @@ mod test {
         ];
 
         let mnemonic = Mnemonic::from_entropy(entropy, Language::English).unwrap();
-        let seed = Seed::new(&mnemonic, "password");
+        let seed = Seed::new(&mnemonic, "password").unwrap();
 
         assert_eq!(format!("{:x}", seed), "0bde96f14c35a66235478e0c16c152fcaf6301e4d9a81d3febc50879fe7e5438e6a8dd3e39bdf3ab7b12d6b44218710e17d7a2844ee9633fab0e03d9a6c8569b");
         assert_eq!(format!("{:X}", seed), "0BDE96F14C35A66235478E0C16C152FCAF6301E4D9A81D3FEBC50879FE7E5438E6A8DD3E39BDF3AB7B12D6B44218710E17D7A2844EE9633FAB0E03D9A6C8569B");
@@ -155,9 +1303,650 @@ mod test {
         assert_eq!(format!("{:#X}", seed), "0x0BDE96F14C35A66235478E0C16C152FCAF6301E4D9A81D3FEBC50879FE7E5438E6A8DD3E39BDF3AB7B12D6B44218710E17D7A2844EE9633FAB0E03D9A6C8569B");
     }
 
+    #[test]
+    fn generic_seed_lengths() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+        let short = GenericSeed::<32>::new(&mnemonic, "password").unwrap();
+        let long = GenericSeed::<100>::new(&mnemonic, "password").unwrap();
+
+        assert_eq!(short.as_array(), &seed.as_array()[..32]);
+        assert_eq!(&long.as_bytes()[..64], seed.as_bytes());
+        assert_eq!(format!("{:x}", short).len(), 64);
+
+        let chunks = short.to_hex_chunks(16);
+        assert!(GenericSeed::<32>::from_hex_chunks(&chunks).unwrap().ct_eq(&short));
+        assert!(Seed::from_hex_chunks(&chunks).is_err());
+
+        let json = serde_json::to_string(&seed.expose()).unwrap();
+        assert!(serde_json::from_str::<Seed>(&json).unwrap().ct_eq(&seed));
+
+        let err = serde_json::from_str::<GenericSeed<32>>(&json).err().unwrap();
+        assert!(err.to_string().contains("invalid seed length: 64 bytes"), "{}", err);
+
+        let binary = bincode::serialize(&short.expose()).unwrap();
+        assert!(bincode::deserialize::<Seed>(&binary).is_err());
+        assert!(bincode::deserialize::<GenericSeed<32>>(&binary).unwrap().ct_eq(&short));
+
+        let from_array = GenericSeed::from_array(*short.as_array());
+        assert!(from_array.ct_eq(&short));
+    }
+
+    #[test]
+    fn seed_try_from_slice() {
+        let bytes: Vec<u8> = (0..64).collect();
+
+        let seed = Seed::try_from(&bytes[..]).unwrap();
+        assert_eq!(seed.as_bytes(), &bytes[..]);
+        assert!(!seed.has_password());
+
+        let seed = GenericSeed::<32>::try_from(&bytes[..32]).unwrap();
+        assert_eq!(seed.as_bytes(), &bytes[..32]);
+
+        for &len in &[0, 32, 63, 65] {
+            let bytes = vec![0; len];
+            let err = Seed::try_from(&bytes[..]).unwrap_err();
+
+            match err.downcast::<ErrorKind>().unwrap() {
+                ErrorKind::InvalidSeedLength(got) => assert_eq!(got, len),
+                kind => panic!("unexpected error: {}", kind),
+            }
+        }
+    }
+
+    #[test]
+    fn seed_len() {
+        let mnemonic = Mnemonic::from_entropy(&[0; 16], Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "").unwrap();
+
+        assert_eq!(seed.len(), 64);
+        assert_eq!(seed.len(), seed.as_bytes().len());
+        assert!(!seed.is_empty());
+
+        assert_eq!(GenericSeed::<32>::new(&mnemonic, "").unwrap().len(), 32);
+    }
+
+    #[test]
+    fn seed_has_password() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let with_password = Seed::new(&mnemonic, "password").unwrap();
+        let without_password = Seed::new(&mnemonic, "").unwrap();
+
+        assert!(with_password.has_password());
+        assert!(!without_password.has_password());
+        assert!(with_password.clone().has_password());
+
+        let seeds = Seed::for_passwords(&mnemonic, &["", "hidden"]).unwrap();
+        assert!(!seeds[0].has_password());
+        assert!(seeds[1].has_password());
+
+        assert!(SeedBuilder::new(&mnemonic).password("password").derive().has_password());
+        assert!(!SeedBuilder::new(&mnemonic).derive().has_password());
+        assert!(Seed::new_legacy_no_normalize(&mnemonic, "password").unwrap().has_password());
+        assert!(!Seed::new_sha256(&mnemonic, "").unwrap().has_password());
+
+        // the flag is never serialized
+        let json = serde_json::to_string(&with_password.expose()).unwrap();
+        assert!(!serde_json::from_str::<Seed>(&json).unwrap().has_password());
+    }
+
+    #[test]
+    fn seed_new_password_reuses_mnemonic() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let old = Seed::new(&mnemonic, "").unwrap();
+        let new = Seed::new(&mnemonic, "new password").unwrap();
+
+        assert!(!old.has_password());
+        assert!(new.has_password());
+        assert!(!new.ct_eq(&old));
+
+        let reparsed = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert!(new.ct_eq(&Seed::new(&reparsed, "new password").unwrap()));
+    }
+
+    #[test]
+    fn seed_derive_prefix() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "päßwörd").unwrap();
+
+        for &len in &[0, 1, 4, 32, 63, 64] {
+            assert_eq!(
+                &Seed::derive_prefix(&mnemonic, "päßwörd", len).unwrap()[..],
+                &seed.as_bytes()[..len]
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "only 64 bytes")]
+    fn seed_derive_prefix_too_long() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        Seed::derive_prefix(&mnemonic, "", 65).unwrap();
+    }
+
+    #[test]
+    fn seed_clone_is_deep() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "").unwrap();
+        let bytes = seed.as_bytes().to_vec();
+
+        let clone = seed.clone();
+
+        assert_ne!(seed.as_bytes().as_ptr(), clone.as_bytes().as_ptr());
+
+        drop(seed);
+
+        assert_eq!(clone.as_bytes(), &bytes[..]);
+    }
+
+    #[test]
+    fn seeds_for_passwords() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let passwords = ["", "password", "nullius　à　nym.zone ¹teſts² English"];
+
+        let seeds = Seed::for_passwords(&mnemonic, &passwords).unwrap();
+
+        assert_eq!(seeds.len(), passwords.len());
+
+        for (seed, password) in seeds.iter().zip(passwords.iter()) {
+            assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, password).unwrap().as_bytes());
+        }
+
+        assert!(Seed::for_passwords(&mnemonic, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn seeds_for_pin_range() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let seeds: Vec<(u64, Seed)> = Seed::for_pin_range(&mnemonic, 98, 102).unwrap().collect();
+        let pins: Vec<u64> = seeds.iter().map(|&(pin, _)| pin).collect();
+
+        assert_eq!(pins, [98, 99, 100, 101]);
+
+        for (pin, seed) in &seeds {
+            let expected = Seed::new(&mnemonic, &pin.to_string()).unwrap();
+
+            assert_eq!(seed.as_bytes(), expected.as_bytes());
+            assert!(seed.has_password());
+        }
+
+        assert_eq!(Seed::for_pin_range(&mnemonic, 5, 5).unwrap().count(), 0);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn seed_new_async() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let seeds = tokio::join!(
+            Seed::new_async(&mnemonic, "password"),
+            Seed::new_async(&mnemonic, "")
+        );
+
+        let seeds = (seeds.0.unwrap(), seeds.1.unwrap());
+
+        assert_eq!(seeds.0.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+        assert_eq!(seeds.1.as_bytes(), Seed::new(&mnemonic, "").unwrap().as_bytes());
+    }
+
+    #[test]
+    fn seed_legacy_no_normalize() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        // "ﬁ" (U+FB01) decomposes to "fi" under NFKD
+        let legacy = Seed::new_legacy_no_normalize(&mnemonic, "ﬁat").unwrap();
+
+        assert_ne!(legacy.as_bytes(), Seed::new(&mnemonic, "ﬁat").unwrap().as_bytes());
+        let unnormalized = SeedBuilder::new(&mnemonic)
+            .password("ﬁat")
+            .normalize(false)
+            .derive();
+        assert_eq!(legacy.as_bytes(), unnormalized.as_bytes());
+        assert_eq!(
+            Seed::new(&mnemonic, "ﬁat").unwrap().as_bytes(),
+            Seed::new_legacy_no_normalize(&mnemonic, "fiat").unwrap().as_bytes()
+        );
+    }
+
+    #[test]
+    fn seed_new_sha256() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        // PBKDF2-HMAC-SHA256 with 2048 rounds and a 64 byte output, computed independently
+        assert_eq!(
+            format!("{:x}", Seed::new_sha256(&mnemonic, "TREZOR").unwrap()),
+            "6b426ccb61e220603bf1bc37bce20ea9ae587440045cee317d2fbc5cf89998ef\
+             d5713b364b9039cf1d50197b8d3153c2ca79a927aaa1606b4071b6e6311cea9a"
+        );
+        assert_eq!(
+            format!("{:x}", Seed::new_sha256(&mnemonic, "").unwrap()),
+            "c61cbf0ed17ece1ca823fd8a9f00a23c3610e23081961bf96fb535c3057da923\
+             7e1353cc65be420b921e1959457c95278c52b7d550343af30671be6817a21e30"
+        );
+    }
+
+    #[test]
+    fn seed_new_with_progress() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let mut reports = Vec::new();
+        let seed = Seed::new_with_progress(&mnemonic, "password", |percent| reports.push(percent));
+        let seed = seed.unwrap();
+
+        assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+        assert_eq!(reports, [0, 100]);
+    }
+
+    #[test]
+    fn seed_derive_with_entropy() {
+        let entropy = [0x42; 32];
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+        let (seed, derived_entropy) = Seed::derive(&mnemonic, "password").unwrap();
+
+        assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "password").unwrap().as_bytes());
+        assert_eq!(derived_entropy, entropy);
+    }
+
+    #[test]
+    fn seed_ct_eq() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        assert!(seed.ct_eq(&seed.clone()));
+        assert!(!seed.ct_eq(&Seed::new(&mnemonic, "").unwrap()));
+
+        let mut bytes = seed.as_bytes().to_vec();
+        assert!(seed.ct_eq_bytes(&bytes));
+
+        bytes[63] ^= 1;
+        assert!(!seed.ct_eq_bytes(&bytes));
+        assert!(!seed.ct_eq_bytes(&seed.as_bytes()[..63]));
+        assert!(!seed.ct_eq_bytes(&[]));
+    }
+
+    #[test]
+    fn seed_derive_subseed() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        assert_eq!(
+            format!("{:x}", seed.derive_subseed(b"")),
+            "827bc702705d8c547bc987e57b342ded59addc87463b6b695e372f4912d473d2\
+             40f701220198f9154d384bbc04a4f648c3664f741b1357c98a893fbacb67728b"
+        );
+        assert_eq!(
+            format!("{:x}", seed.derive_subseed(b"example.com/signing")),
+            "1ae4803282f531b753bba6b0e17fdae93eeb330060f9cbe1a684edc25088a3d2\
+             ccbe9f64f9c737c87fa163d5fe57ef28315716d5d97825b2b36e9e823b84ea3e"
+        );
+    }
+
+    #[test]
+    fn seed_try_into_array() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+        let expected = seed.as_bytes().to_vec();
+
+        let seed = seed.try_into_array::<63>().unwrap_err();
+        let seed = seed.try_into_array::<65>().unwrap_err();
+        assert_eq!(seed.as_bytes(), &expected[..]);
+
+        let array: [u8; 64] = seed.try_into_array().unwrap();
+        assert_eq!(&array[..], &expected[..]);
+    }
+
+    #[test]
+    fn seed_hex_chunks_round_trip() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        for &chunk_len in &[1, 7, 16, 30, 64, 128, 200] {
+            let chunks = seed.to_hex_chunks(chunk_len);
+
+            assert_eq!(chunks.len(), 128usize.div_ceil(chunk_len));
+            assert!(chunks.iter().all(|chunk| chunk.len() <= chunk_len));
+            assert_eq!(chunks.concat(), format!("{:x}", seed));
+
+            let restored = Seed::from_hex_chunks(&chunks).unwrap();
+            assert_eq!(restored.as_bytes(), seed.as_bytes());
+        }
+    }
+
+    #[test]
+    fn seed_from_invalid_hex_chunks() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let mut chunks = seed.to_hex_chunks(32);
+        chunks.pop();
+
+        let err = Seed::from_hex_chunks(&chunks).unwrap_err();
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidSeedLength(48)
+        ));
+
+        let mut chunks = seed.to_hex_chunks(32);
+        chunks[1].replace_range(..1, "z");
+
+        let err = Seed::from_hex_chunks(&chunks).unwrap_err();
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::InvalidHex(_)
+        ));
+    }
+
+    #[test]
+    fn seed_serde_binary() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let encoded = bincode::serialize(&seed.expose()).unwrap();
+
+        // u64 length prefix, version byte, seed bytes
+        assert_eq!(encoded.len(), 8 + 1 + 64);
+        assert_eq!(encoded[8], serde_seed::FORMAT_VERSION);
+
+        let decoded: Seed = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.as_bytes(), seed.as_bytes());
+
+        let mut unknown_version = encoded.clone();
+        unknown_version[8] = 2;
+        let err = bincode::deserialize::<Seed>(&unknown_version).unwrap_err();
+        assert!(err.to_string().contains("unsupported seed format version 2"));
+
+        let empty = bincode::serialize(&Vec::<u8>::new()).unwrap();
+        let err = bincode::deserialize::<Seed>(&empty).unwrap_err();
+        assert!(err.to_string().contains("missing seed format version"));
+    }
+
+    #[test]
+    fn seed_serde_cbor_byte_string() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let mut encoded = Vec::new();
+        ciborium::ser::into_writer(&seed.expose(), &mut encoded).unwrap();
+
+        // a map with the single text key "bytes", then a byte string (major type 2) with a
+        // one byte length of 65: the version byte and the seed bytes
+        let mut expected = vec![0xa1, 0x65];
+        expected.extend_from_slice(b"bytes");
+        expected.extend_from_slice(&[0x58, 65, serde_seed::FORMAT_VERSION]);
+        expected.extend_from_slice(seed.as_bytes());
+        assert_eq!(encoded, expected);
+
+        let value: ciborium::value::Value = ciborium::de::from_reader(&encoded[..]).unwrap();
+        let entries = value.as_map().unwrap();
+        assert_eq!(entries[0].0.as_text(), Some("bytes"));
+        assert_eq!(entries[0].1.as_bytes().map(|bytes| bytes.len()), Some(65));
+        assert!(!entries[0].1.is_text());
+
+        let decoded: Seed = ciborium::de::from_reader(&encoded[..]).unwrap();
+        assert!(decoded.ct_eq(&seed));
+    }
+
+    #[test]
+    fn seed_serde_json_is_plain_hex() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let json = serde_json::to_string(&seed.expose()).unwrap();
+
+        assert_eq!(json, format!("{{\"bytes\":\"{:x}\"}}", seed));
+        assert_eq!(serde_json::from_str::<Seed>(&json).unwrap().as_bytes(), seed.as_bytes());
+
+        // the `UpperHex` output is read back too
+        let upper = format!("{{\"bytes\":\"{:#X}\"}}", seed);
+        assert_eq!(serde_json::from_str::<Seed>(&upper).unwrap().as_bytes(), seed.as_bytes());
+    }
+
+    #[test]
+    fn seed_with_hex() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        let expected = "0bde96f14c35a66235478e0c16c152fcaf6301e4d9a81d3febc50879fe7e5438e6a8dd3e39bdf3ab7b12d6b44218710e17d7a2844ee9633fab0e03d9a6c8569b";
+        assert!(seed.with_hex(|hex| hex == expected));
+        assert_eq!(seed.with_hex(|hex| hex.len()), 128);
+
+        let short = GenericSeed::<32>::new(&mnemonic, "password").unwrap();
+        short.with_hex(|hex| assert_eq!(hex, &expected[..64]));
+    }
+
+    #[test]
+    fn seed_from_hex_any_case() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        for hex in &[
+            format!("{:x}", seed),
+            format!("{:X}", seed),
+            format!("{:#x}", seed),
+            format!("{:#X}", seed),
+            format!("0X{:x}", seed),
+        ] {
+            assert_eq!(Seed::from_hex(hex).unwrap().as_bytes(), seed.as_bytes(), "{}", hex);
+        }
+
+        let mixed: String = format!("{:x}", seed)
+            .chars()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 0 { c.to_ascii_uppercase() } else { c })
+            .collect();
+        assert!(mixed.starts_with("0bDe96F1"));
+        assert_eq!(Seed::from_hex(&mixed).unwrap().as_bytes(), seed.as_bytes());
+
+        let err = Seed::from_hex("0x0G").unwrap_err();
+        assert!(matches!(err.downcast::<ErrorKind>().unwrap(), ErrorKind::InvalidHex(_)));
+
+        let err = Seed::from_hex(&format!("{:x}", seed)[..126]).unwrap_err();
+        let kind = err.downcast::<ErrorKind>().unwrap();
+        assert!(matches!(kind, ErrorKind::InvalidSeedLength(63)));
+
+        // only a single prefix is stripped
+        assert!(Seed::from_hex(&format!("0x0x{:x}", seed)).is_err());
+    }
+
+    #[test]
+    fn seed_serialize_redacted() {
+        #[derive(Serialize)]
+        struct Account {
+            seed: Seed,
+        }
+
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let account = Account { seed: Seed::new(&mnemonic, "password").unwrap() };
+
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(json, r#"{"seed":"<redacted>"}"#);
+
+        let binary = bincode::serialize(&account).unwrap();
+        assert_eq!(bincode::deserialize::<String>(&binary).unwrap(), "<redacted>");
+
+        // the placeholder can't be mistaken for a seed
+        assert!(serde_json::from_str::<Seed>(r#""<redacted>""#).is_err());
+        assert!(bincode::deserialize::<Seed>(&binary).is_err());
+    }
+
+    #[test]
+    fn seed_debug_redacted() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "password").unwrap();
+
+        assert_eq!(
+            format!("{:?}", seed),
+            "GenericSeed { bytes: <redacted>, has_password: true }"
+        );
+        assert_eq!(format!("{:?}", seed.expose()), format!("{:#X}", seed));
+    }
+
+    #[test]
+    fn seed_builder_defaults() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let seed = SeedBuilder::new(&mnemonic).password("päßwörd").derive();
+
+        assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, "päßwörd").unwrap().as_bytes());
+    }
+
+    #[test]
+    fn seed_builder_custom() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let standard = Seed::new(&mnemonic, "päßwörd").unwrap();
+
+        let builder = SeedBuilder::new(&mnemonic).password("päßwörd");
+
+        // U+3000 is normalized to a regular space
+        let ideographic = builder.clone().separator("\u{3000}").derive();
+        assert_eq!(ideographic.as_bytes(), standard.as_bytes());
+
+        let raw = builder.clone().separator("\u{3000}").normalize(false).derive();
+        assert_ne!(raw.as_bytes(), standard.as_bytes());
+
+        let unnormalized = builder.clone().normalize(false).derive();
+        assert_ne!(unnormalized.as_bytes(), standard.as_bytes());
+
+        let iterations = builder.iterations(1).derive();
+        assert_ne!(iterations.as_bytes(), standard.as_bytes());
+    }
+
+    #[test]
+    fn seed_builder_derivation_params() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let params = SeedBuilder::new(&mnemonic).password("päßwörd").derivation_params();
+        assert_eq!(
+            params,
+            DerivationParams {
+                language: Language::English,
+                word_count: 12,
+                iterations: 2048,
+                hash: "SHA512",
+                normalization: NormalizationForm::Nfkd,
+                separator: " ".to_owned(),
+            }
+        );
+        assert!(params.is_standard());
+        assert!(!format!("{} {:?}", params, params).contains("päßwörd"));
+
+        let params = SeedBuilder::new(&mnemonic)
+            .separator("\u{3000}")
+            .normalize(false)
+            .iterations(1)
+            .derivation_params();
+        assert!(!params.is_standard());
+        assert_eq!(
+            params.to_string(),
+            "English, 12 words, PBKDF2-HMAC-SHA512 with 1 iterations, not normalized, \
+             words separated by \"\\u{3000}\""
+        );
+    }
+
+    #[test]
+    fn seed_builder_normalization_form() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        let derive = |password, form| {
+            SeedBuilder::new(&mnemonic)
+                .password(password)
+                .normalization_form(form)
+                .derive()
+        };
+
+        // NFC composes "n" and a combining tilde into "ñ", and keeps the compatibility
+        // characters "ﬁ" and "２" that NFKD replaces with "fi" and "2"
+        let password = "ﬁn\u{303}２";
+        let nfkd = derive(password, NormalizationForm::Nfkd);
+        let nfc = derive(password, NormalizationForm::Nfc);
+
+        assert_ne!(nfc.as_bytes(), nfkd.as_bytes());
+        assert_eq!(nfkd.as_bytes(), Seed::new(&mnemonic, password).unwrap().as_bytes());
+        assert_eq!(
+            nfkd.as_bytes(),
+            derive("fin\u{303}2", NormalizationForm::None).as_bytes()
+        );
+        assert_eq!(nfc.as_bytes(), derive("ﬁñ２", NormalizationForm::None).as_bytes());
+        assert_eq!(
+            derive(password, NormalizationForm::None).as_bytes(),
+            SeedBuilder::new(&mnemonic).password(password).normalize(false).derive().as_bytes()
+        );
+
+        let params = SeedBuilder::new(&mnemonic)
+            .normalization_form(NormalizationForm::Nfc)
+            .derivation_params();
+        assert!(!params.is_standard());
+        assert!(params.to_string().contains("NFC normalized"));
+    }
+
+    #[test]
+    fn seed_builder_max_password_len() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        // 5 characters, 8 bytes
+        let password = "päßö!";
+        let builder = SeedBuilder::new(&mnemonic).password(password);
+
+        let seed = builder.clone().max_password_len(8).try_derive().unwrap();
+        assert_eq!(seed.as_bytes(), Seed::new(&mnemonic, password).unwrap().as_bytes());
+
+        let err = builder.clone().max_password_len(7).try_derive().unwrap_err();
+        assert!(matches!(
+            err.downcast::<ErrorKind>().unwrap(),
+            ErrorKind::PasswordTooLong { len: 8, max: 7 }
+        ));
+
+        assert!(builder.max_password_len(0).try_derive().is_err());
+        assert!(SeedBuilder::new(&mnemonic).max_password_len(0).try_derive().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "password of 8 bytes exceeds the limit of 4 bytes")]
+    fn seed_builder_derive_password_too_long() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        SeedBuilder::new(&mnemonic).password("password").max_password_len(4).derive();
+    }
+
     fn test_unicode_normalization(lang: Language, phrase: &str, password: &str, expected_seed_hex: &str) {
         let mnemonic = Mnemonic::from_phrase(phrase, lang).unwrap();
-        let seed = Seed::new(&mnemonic, password);
+        let seed = Seed::new(&mnemonic, password).unwrap();
         assert_eq!(format!("{:x}", seed), expected_seed_hex);
     }
 
@@ -217,4 +2006,26 @@ mod test {
             "cff9ffd2b23549e73601db4129a334c81b28a40f0ee819b5d6a54c409999f0dfb6b89df17cae6408c96786165c205403d283baadc03ffdd391a490923b7d9493",
         );
     }
+
+    #[test]
+    fn seed_is_locked() {
+        let mnemonic = Mnemonic::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            Language::English,
+        )
+        .unwrap();
+        let seed = Seed::new(&mnemonic, "TREZOR").unwrap();
+        let clone = seed.clone();
+
+        assert_eq!(
+            format!("{:x}", clone),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+        assert_eq!(clone.as_bytes(), seed.as_bytes());
+        assert_eq!(clone.is_locked(), seed.is_locked());
+
+        if cfg!(not(feature = "mlock")) {
+            assert!(!seed.is_locked());
+        }
+    }
 }
@@ -0,0 +1,192 @@
+//! Generating a mnemonic one word at a time, for UIs too constrained to hold the whole phrase
+//!
+//! [`Mnemonic::generate_streaming()`][Mnemonic::generate_streaming()] draws the entropy up
+//! front and computes each word only when it is asked for. The phrase string is only built if
+//! the [`EntropyHandle`][EntropyHandle] is finalized into a [`Mnemonic`][Mnemonic].
+//!
+//! [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+//! [Mnemonic::generate_streaming()]: ./mnemonic/struct.Mnemonic.html#method.generate_streaming
+//! [EntropyHandle]: ./struct.EntropyHandle.html
+use std::mem;
+use failure::Error;
+use zeroize::Zeroize;
+use crate::checksum::Checksum;
+use crate::crypto::gen_random_bytes;
+use crate::language::{Language, WordList};
+use crate::mnemonic::Mnemonic;
+use crate::mnemonic_type::MnemonicType;
+use crate::util::Bits11;
+
+/// The entropy of a mnemonic being generated with
+/// [`Mnemonic::generate_streaming()`][Mnemonic::generate_streaming()]
+///
+/// The entropy is wiped from memory when the handle is dropped.
+///
+/// [Mnemonic::generate_streaming()]: ./mnemonic/struct.Mnemonic.html#method.generate_streaming
+pub struct EntropyHandle {
+    entropy: Vec<u8>,
+    lang: Language,
+}
+
+impl EntropyHandle {
+    /// The [`MnemonicType`][MnemonicType] of the mnemonic being generated
+    ///
+    /// [MnemonicType]: ../mnemonic_type/struct.MnemonicType.html
+    pub fn mnemonic_type(&self) -> MnemonicType {
+        MnemonicType::for_key_size(self.entropy.len() * 8)
+            .expect("the entropy of a handle has a valid length")
+    }
+
+    /// Build the [`Mnemonic`][Mnemonic], with the same words as the streamed ones
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    pub fn finalize(mut self) -> Mnemonic {
+        Mnemonic::from_entropy_unchecked(mem::take(&mut self.entropy), self.lang)
+    }
+}
+
+impl Drop for EntropyHandle {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+/// The words of the phrase, each computed from 11 bits of the entropy and checksum when needed
+struct StreamingWords {
+    /// The entropy followed by a byte holding the checksum in its most significant bits
+    bits: Vec<u8>,
+    next: usize,
+    count: usize,
+    wordlist: &'static WordList,
+}
+
+impl Iterator for StreamingWords {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        if self.next == self.count {
+            return None;
+        }
+
+        // the 11 bits of a word span at most 3 bytes
+        let offset = self.next * 11;
+        let start = offset / 8;
+        let mut window = 0u32;
+        for i in 0..3 {
+            window = (window << 8) | u32::from(self.bits.get(start + i).copied().unwrap_or(0));
+        }
+        let index = (window >> (24 - 11 - offset % 8)) & 0x7ff;
+
+        self.next += 1;
+
+        Some(self.wordlist.get_word(Bits11::from(index as u16)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.next;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for StreamingWords {}
+
+impl Drop for StreamingWords {
+    fn drop(&mut self) {
+        self.bits.zeroize();
+    }
+}
+
+impl Mnemonic {
+    /// Generates a new mnemonic whose words are computed one at a time, as they are iterated
+    ///
+    /// Returns the [`EntropyHandle`][EntropyHandle] holding the random entropy, and an iterator
+    /// over the words of the phrase. Iterating never allocates, so a UI can render the words one
+    /// by one without materializing the whole phrase. Call
+    /// [`EntropyHandle::finalize()`][EntropyHandle::finalize()] to get the full
+    /// [`Mnemonic`][Mnemonic] once it is needed.
+    ///
+    /// Returns an `Error` of kind `ErrorKind::RngFailure` if the system RNG keeps failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, MnemonicType, Language};
+    ///
+    /// let (handle, words) = Mnemonic::generate_streaming(MnemonicType::Words12, Language::English).unwrap();
+    ///
+    /// for (number, word) in words.enumerate() {
+    ///     println!("{}. {}", number + 1, word);
+    /// }
+    ///
+    /// let mnemonic = handle.finalize();
+    /// ```
+    ///
+    /// [Mnemonic]: ./mnemonic/struct.Mnemonic.html
+    /// [EntropyHandle]: ./struct.EntropyHandle.html
+    /// [EntropyHandle::finalize()]: ./struct.EntropyHandle.html#method.finalize
+    pub fn generate_streaming(
+        mtype: MnemonicType,
+        lang: Language,
+    ) -> Result<(EntropyHandle, impl Iterator<Item = &'static str>), Error> {
+        Ok(Mnemonic::generate_streaming_from(gen_random_bytes(mtype.entropy_bytes())?, lang))
+    }
+
+    fn generate_streaming_from(
+        entropy: Vec<u8>,
+        lang: Language,
+    ) -> (EntropyHandle, impl Iterator<Item = &'static str>) {
+        let checksum_bits = Checksum::bit_width(entropy.len());
+
+        let mut bits = Vec::with_capacity(entropy.len() + 1);
+        bits.extend_from_slice(&entropy);
+        bits.push(Checksum::compute(&entropy) << (8 - checksum_bits));
+
+        let words = StreamingWords {
+            bits,
+            next: 0,
+            count: (entropy.len() * 8 + checksum_bits) / 11,
+            wordlist: lang.wordlist(),
+        };
+
+        (EntropyHandle { entropy, lang }, words)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn streaming_words_match_phrase() {
+        for &mtype in &[
+            MnemonicType::Words12,
+            MnemonicType::Words15,
+            MnemonicType::Words18,
+            MnemonicType::Words21,
+            MnemonicType::Words24,
+        ] {
+            let (handle, words) = Mnemonic::generate_streaming(mtype, Language::English).unwrap();
+            let words: Vec<&str> = words.collect();
+
+            assert_eq!(handle.mnemonic_type(), mtype);
+            assert_eq!(words.len(), mtype.word_count());
+            assert_eq!(words.join(" "), handle.finalize().phrase());
+        }
+    }
+
+    #[test]
+    fn streaming_words_vector() {
+        let entropy = vec![0x7f; 16];
+        let (handle, mut words) = Mnemonic::generate_streaming_from(entropy, Language::English);
+
+        assert_eq!(words.size_hint(), (12, Some(12)));
+        assert_eq!(words.next(), Some("legal"));
+        assert_eq!(words.size_hint(), (11, Some(11)));
+        assert_eq!(words.last(), Some("yellow"));
+        assert_eq!(
+            handle.finalize().phrase(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+    }
+}
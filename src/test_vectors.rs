@@ -0,0 +1,100 @@
+//! The official BIP39 test vectors, with the `test-vectors` feature
+//!
+//! Their phrases are public, so any funds sent to the addresses of their seeds are lost to
+//! whoever sweeps them first. Demos and test harnesses can use
+//! [`Mnemonic::test_vector_match()`][Mnemonic::test_vector_match()] to warn about them.
+//!
+//! [Mnemonic::test_vector_match()]: ./mnemonic/struct.Mnemonic.html#method.test_vector_match
+use crate::language::Language;
+use crate::mnemonic::Mnemonic;
+
+/// The English phrases of <https://github.com/trezor/python-mnemonic/blob/master/vectors.json>,
+/// in order
+const ENGLISH_VECTORS: [&str; 24] = [
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+    "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+    "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+    "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+    "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+    "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+    "gravity machine north sort system female filter attitude volume fold club stay feature office ecology stable narrow fog",
+    "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+    "scheme spot photo card baby mountain device kick cradle pact join borrow",
+    "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap uncle crack brave",
+    "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+    "cat swing flag economy stadium alone churn speed unique patch report train",
+    "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart sustain crack supply proud access",
+    "all hour make first leader extend hole alien behind guard gospel lava path output census museum junior mass reopen famous sing advance salt reform",
+    "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+    "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little brisk hair mango congress clump",
+    "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+];
+
+impl Mnemonic {
+    /// The index of `phrase` in the official BIP39 test vectors, if it is one of them
+    ///
+    /// The phrase is normalized like in [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()]
+    /// first. The official vectors are all English, any other `lang` gives `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    ///
+    /// if let Some(index) = Mnemonic::test_vector_match(phrase, Language::English) {
+    ///     println!("test vector #{}, do not store funds here!", index);
+    /// }
+    /// # assert_eq!(Mnemonic::test_vector_match(phrase, Language::English), Some(1));
+    /// ```
+    ///
+    /// [Mnemonic::from_phrase()]: ./mnemonic/struct.Mnemonic.html#method.from_phrase
+    pub fn test_vector_match(phrase: &str, lang: Language) -> Option<usize> {
+        if lang != Language::English {
+            return None;
+        }
+
+        let phrase = Mnemonic::normalize_phrase(phrase);
+
+        ENGLISH_VECTORS.iter().position(|&vector| vector == phrase)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vector_matches() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(Mnemonic::test_vector_match(phrase, Language::English), Some(0));
+
+        let phrase = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+        assert_eq!(Mnemonic::test_vector_match(phrase, Language::English), Some(23));
+
+        let spaced = "  zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo\twrong ";
+        assert_eq!(Mnemonic::test_vector_match(spaced, Language::English), Some(3));
+    }
+
+    #[test]
+    fn test_vector_no_match() {
+        let mnemonic = Mnemonic::new(crate::MnemonicType::Words12, Language::English).unwrap();
+        assert_eq!(Mnemonic::test_vector_match(mnemonic.phrase(), Language::English), None);
+
+        #[cfg(feature = "french")]
+        {
+            let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+            assert_eq!(Mnemonic::test_vector_match(phrase, Language::French), None);
+        }
+
+        assert_eq!(Mnemonic::test_vector_match("", Language::English), None);
+    }
+}
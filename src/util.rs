@@ -1,4 +1,5 @@
 use unicode_normalization::Decompositions;
+use crate::error::{ErrorKind, HexError};
 
 pub(crate) trait IterExt: Iterator {
     fn join<R>(&mut self, glue: &str) -> R
@@ -69,7 +70,7 @@ impl Bits for u8 {
     }
 }
 
-impl<'a> Bits for &'a u8 {
+impl Bits for &u8 {
     const SIZE: usize = 8;
 
     fn bits(self) -> u32 {
@@ -108,11 +109,7 @@ pub(crate) struct BitWriter {
 
 impl BitWriter {
     pub fn with_capacity(capacity: usize) -> Self {
-        let mut bytes = capacity / 8;
-
-        if capacity % 8 != 0 {
-            bytes += 1;
-        }
+        let bytes = capacity.div_ceil(8);
 
         Self {
             offset: 0,
@@ -212,3 +209,30 @@ pub(crate) fn checksum(source: u8, bits: u8) -> u8 {
 
     source >> (8 - bits)
 }
+
+/// Decode a hex string, with an optional `0x` or `0X` prefix, into bytes
+///
+/// Digits are case insensitive, so that both `{:x}` and `{:X}` output can be read back.
+pub(crate) fn from_hex(hex: &str) -> Result<Vec<u8>, ErrorKind> {
+    let hex = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+
+    if !hex.len().is_multiple_of(2) {
+        return Err(ErrorKind::InvalidHex(HexError::OddLength(hex.len())));
+    }
+
+    let nibbles = hex
+        .chars()
+        .enumerate()
+        .map(|(index, digit)| {
+            digit
+                .to_digit(16)
+                .map(|n| n as u8)
+                .ok_or(ErrorKind::InvalidHex(HexError::InvalidDigit { index, digit }))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    Ok(nibbles.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
@@ -5,13 +5,13 @@ use bip39::{Language, Mnemonic, MnemonicType, Seed};
 fn test_word_count(expected_word_count: usize) {
     let mnemonic_type = MnemonicType::for_word_count(expected_word_count).unwrap();
 
-    let mnemonic = Mnemonic::new(mnemonic_type, Language::English);
+    let mnemonic = Mnemonic::new(mnemonic_type, Language::English).unwrap();
     let actual_word_count = mnemonic.phrase().split(" ").count();
 
     assert_eq!(actual_word_count, expected_word_count);
     assert_eq!(mnemonic_type.word_count(), expected_word_count);
 
-    let seed = Seed::new(&mnemonic, "");
+    let seed = Seed::new(&mnemonic, "").unwrap();
     let seed_bytes: &[u8] = seed.as_bytes();
 
     assert!(seed_bytes.len() == 64);
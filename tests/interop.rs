@@ -0,0 +1,96 @@
+//! Cross-checks phrases and seeds against the `bip39` crate of the rust-bitcoin project, so
+//! that any divergence in normalization or key derivation shows up as a test failure.
+
+extern crate bip39;
+extern crate bip39_reference;
+
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+
+const PASSWORDS: &[&str] = &[
+    "",
+    "TREZOR",
+    "correct horse battery staple",
+    "ñandú",
+    "パスワード",
+];
+
+fn reference_language(lang: Language) -> bip39_reference::Language {
+    match lang {
+        Language::English => bip39_reference::Language::English,
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified => bip39_reference::Language::SimplifiedChinese,
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional => bip39_reference::Language::TraditionalChinese,
+        #[cfg(feature = "french")]
+        Language::French => bip39_reference::Language::French,
+        #[cfg(feature = "italian")]
+        Language::Italian => bip39_reference::Language::Italian,
+        #[cfg(feature = "japanese")]
+        Language::Japanese => bip39_reference::Language::Japanese,
+        #[cfg(feature = "korean")]
+        Language::Korean => bip39_reference::Language::Korean,
+        #[cfg(feature = "spanish")]
+        Language::Spanish => bip39_reference::Language::Spanish,
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("no reference language for {:?}", lang),
+    }
+}
+
+#[test]
+fn interop_random_mnemonics() {
+    let mtypes = [
+        MnemonicType::Words12,
+        MnemonicType::Words15,
+        MnemonicType::Words18,
+        MnemonicType::Words21,
+        MnemonicType::Words24,
+    ];
+
+    for &lang in Language::all() {
+        for &mtype in mtypes.iter() {
+            let mnemonic = Mnemonic::new(mtype, lang).unwrap();
+            let reference =
+                bip39_reference::Mnemonic::parse_in(reference_language(lang), mnemonic.phrase())
+                    .unwrap();
+
+            // `to_entropy()` of the reference crate re-detects the language, which fails for
+            // Chinese phrases whose words are all shared by both Chinese word lists
+            let from_entropy = bip39_reference::Mnemonic::from_entropy_in(
+                reference_language(lang),
+                mnemonic.entropy(),
+            )
+            .unwrap();
+            assert_eq!(from_entropy, reference, "{:?}", lang);
+
+            for password in PASSWORDS {
+                let seed = Seed::new(&mnemonic, password).unwrap();
+
+                assert_eq!(
+                    &reference.to_seed(*password)[..],
+                    seed.as_bytes(),
+                    "seed mismatch for {:?} phrase '{}' with password '{}'",
+                    lang,
+                    mnemonic.phrase(),
+                    password
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn interop_reference_phrases() {
+    for &lang in Language::all() {
+        let entropy: Vec<u8> = (0..32).map(|_| rand::random()).collect();
+        let reference =
+            bip39_reference::Mnemonic::from_entropy_in(reference_language(lang), &entropy).unwrap();
+
+        let mnemonic = Mnemonic::from_phrase(&reference.to_string(), lang).unwrap();
+
+        assert_eq!(mnemonic.entropy(), &entropy[..]);
+        assert_eq!(
+            &reference.to_seed("TREZOR")[..],
+            Seed::new(&mnemonic, "TREZOR").unwrap().as_bytes()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+extern crate bip39;
+extern crate proptest;
+extern crate sha2;
+
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use proptest::prelude::*;
+use sha2::{Digest, Sha256};
+
+const ENGLISH: &str = include_str!("../src/langs/english.txt");
+
+fn mnemonic_type() -> impl Strategy<Value = MnemonicType> {
+    prop_oneof![
+        Just(MnemonicType::Words12),
+        Just(MnemonicType::Words15),
+        Just(MnemonicType::Words18),
+        Just(MnemonicType::Words21),
+        Just(MnemonicType::Words24),
+    ]
+}
+
+fn entropy() -> impl Strategy<Value = Vec<u8>> {
+    mnemonic_type().prop_flat_map(|mtype| {
+        proptest::collection::vec(any::<u8>(), mtype.entropy_bits() / 8)
+    })
+}
+
+/// Word indices for everything but the checksum bits, plus the entropy they encode
+fn indices() -> impl Strategy<Value = (Vec<u16>, Vec<u8>)> {
+    mnemonic_type().prop_flat_map(|mtype| {
+        let checksum_bits = mtype.checksum_bits() as u32;
+
+        (
+            proptest::collection::vec(0..2048u16, mtype.word_count() - 1),
+            0..(1u16 << (11 - checksum_bits)),
+        )
+            .prop_map(move |(mut indices, last)| {
+                let entropy = pack(&indices, last, 11 - checksum_bits);
+                let checksum = Sha256::digest(&entropy)[0] >> (8 - checksum_bits);
+
+                indices.push((last << checksum_bits) | checksum as u16);
+
+                (indices, entropy)
+            })
+    })
+}
+
+/// Naive reference bit packing, independent from the one in the crate
+fn pack(indices: &[u16], last: u16, last_bits: u32) -> Vec<u8> {
+    let mut bits = Vec::new();
+
+    for index in indices {
+        bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+    }
+    bits.extend((0..last_bits).rev().map(|i| (last >> i) & 1 == 1));
+
+    bits.chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn entropy_round_trip(entropy in entropy()) {
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+        prop_assert_eq!(mnemonic.entropy(), &entropy[..]);
+
+        let restored = Mnemonic::from_phrase(mnemonic.phrase(), Language::English).unwrap();
+
+        prop_assert_eq!(restored.entropy(), &entropy[..]);
+        prop_assert_eq!(restored.phrase(), mnemonic.phrase());
+    }
+
+    #[test]
+    fn checksummed_indices_validate((indices, entropy) in indices()) {
+        let words: Vec<&str> = ENGLISH.split_whitespace().collect();
+        let phrase = indices
+            .iter()
+            .map(|&i| words[i as usize])
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mnemonic = Mnemonic::from_phrase(&phrase, Language::English).unwrap();
+
+        prop_assert_eq!(mnemonic.entropy(), &entropy[..]);
+    }
+}
+
+proptest! {
+    // PBKDF2 is slow in debug builds, so fewer cases here
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn seed_is_deterministic(entropy in entropy(), password in "\\PC{0,16}") {
+        let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+        let restored = Mnemonic::from_phrase(mnemonic.phrase(), Language::English).unwrap();
+
+        let first = Seed::new(&mnemonic, &password).unwrap();
+        let second = Seed::new(&mnemonic, &password).unwrap();
+        let third = Seed::new(&restored, &password).unwrap();
+
+        prop_assert_eq!(first.as_bytes(), second.as_bytes());
+        prop_assert_eq!(first.as_bytes(), third.as_bytes());
+    }
+}
@@ -1,7 +1,8 @@
 extern crate bip39;
 extern crate hex;
 
-use bip39::{Language, Mnemonic, Seed};
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use sha2::{Digest, Sha256};
 
 fn test_mnemonic(entropy_hex: &str, expected_phrase: &str) {
     let entropy_bytes = hex::decode(entropy_hex).unwrap();
@@ -16,7 +17,7 @@ fn test_seed(phrase: &str, password: &str, expected_seed_hex: &str) {
 
     assert!(mnemonic.is_ok());
 
-    let seed = Seed::new(&mnemonic.unwrap(), password);
+    let seed = Seed::new(&mnemonic.unwrap(), password).unwrap();
     let actual_seed_bytes: &[u8] = seed.as_bytes();
     let expected_seed_bytes = hex::decode(expected_seed_hex).unwrap();
 
@@ -198,3 +199,160 @@ tests! {
         "xprv9s21ZrQH143K39rnQJknpH1WEPFJrzmAqqasiDcVrNuk926oizzJDDQkdiTvNPr2FYDYzWgiMiC63YmfPAa2oPyNB23r2g7d1yiK6WpqaQS"
     ]
 }
+
+/// Vectors for the 160 and 224 bit entropy sizes (15 and 21 words), which the Trezor vectors
+/// don't cover. Generated with an independent implementation, using the password "TREZOR".
+const EXTRA_VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "0000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon address",
+        "fa08713f46bf5cb48728ceb70e3aae1bc53c5cb7b4e29c5610261d1cbb7be3bed4d805256fec515754d2be35974fc5da678168e9d9bb0cb70948026923b0def3",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage wise",
+        "f938c2f3ebd11f1c9057b713d977b5260e4282a57811ab163a9708c4ce15307983ac24c4451c7cb353b2002d0a1ee8a404fa59f0f6aa8323fa9bb61248cf4808",
+    ),
+    (
+        "8080808080808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor accident",
+        "bc40a19ec918698b32e3e13ed906006d9e3b9987ba7dee6fc53a824774cc5be68f89b865bbfbac21b2fb99c016e214f54f239f77dd99881c1b81de275c60be3d",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrist",
+        "bfee6f9d2bcfa1331bd6482a24abca521e5f7e769498b9a0146672194c7356e4e409be22bc379c8b64fee2aa24b54d3ec20d10a083eaa5d1d6b4b365941ad37c",
+    ),
+    (
+        "00000000000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon admit",
+        "e7dadc189d2e8d07ac278d9ec98a1d2d327e4a6b7df494c00cbf2cbf2d3543dac7000fc72d4ada8d9997dc8db388ff22c6d79f604a7455f2df5534a28eee04c6",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year viable",
+        "99c0597b2bef5ca4859e21075fee0fc931747a30469b6f564d95f74913c357aceb55221b4f4fe6965e871340b45754b1ae59e53da1797b69b30c5fa40ec105b8",
+    ),
+    (
+        "80808080808080808080808080808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd apart",
+        "708f0487a927474944ed882e5f05954656bd82bebcf4119b1233e90ee8b27b16d48a77be2c2aceecc32b07a94a5e9a04d94856a2b9fd7c2362ac4153420ef2e6",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo veteran",
+        "4aa0af4ca02ef1d9fa675cd02aa06d318425564e7fadd3d51b6165cc56d77398f28d8522073cd036c2a4a24a83e919211c84500d96cb120084e613ff5fcd96c1",
+    ),
+];
+
+#[test]
+fn test_160_and_224_bit_entropy() {
+    for (entropy_hex, phrase, seed_hex) in EXTRA_VECTORS {
+        test_mnemonic(entropy_hex, phrase);
+        test_seed(phrase, "TREZOR", seed_hex);
+    }
+}
+
+/// The all-zeros and all-ones entropy vectors of every length, where off-by-one errors in the
+/// bit packing and checksum show up first: each is `(type, last word of the zeros phrase, its
+/// seed, last word of the ones phrase, its seed)`, seeds with the password "TREZOR". The other
+/// words are all "abandon" (index 0) and "zoo" (index 2047) respectively.
+const EDGE_VECTORS: &[(MnemonicType, &str, &str, &str, &str)] = &[
+    (
+        MnemonicType::Words12,
+        "about",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        "wrong",
+        "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    ),
+    (
+        MnemonicType::Words15,
+        "address",
+        "fa08713f46bf5cb48728ceb70e3aae1bc53c5cb7b4e29c5610261d1cbb7be3bed4d805256fec515754d2be35974fc5da678168e9d9bb0cb70948026923b0def3",
+        "wrist",
+        "bfee6f9d2bcfa1331bd6482a24abca521e5f7e769498b9a0146672194c7356e4e409be22bc379c8b64fee2aa24b54d3ec20d10a083eaa5d1d6b4b365941ad37c",
+    ),
+    (
+        MnemonicType::Words18,
+        "agent",
+        "035895f2f481b1b0f01fcf8c289c794660b289981a78f8106447707fdd9666ca06da5a9a565181599b79f53b844d8a71dd9f439c52a3d7b3e8a79c906ac845fa",
+        "when",
+        "0cd6e5d827bb62eb8fc1e262254223817fd068a74b5b449cc2f667c3f1f985a76379b43348d952e2265b4cd129090758b3e3c2c49103b5051aac2eaeb890a528",
+    ),
+    (
+        MnemonicType::Words21,
+        "admit",
+        "e7dadc189d2e8d07ac278d9ec98a1d2d327e4a6b7df494c00cbf2cbf2d3543dac7000fc72d4ada8d9997dc8db388ff22c6d79f604a7455f2df5534a28eee04c6",
+        "veteran",
+        "4aa0af4ca02ef1d9fa675cd02aa06d318425564e7fadd3d51b6165cc56d77398f28d8522073cd036c2a4a24a83e919211c84500d96cb120084e613ff5fcd96c1",
+    ),
+    (
+        MnemonicType::Words24,
+        "art",
+        "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+        "vote",
+        "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+    ),
+];
+
+#[test]
+fn test_all_zeros_and_all_ones_entropy() {
+    for &(mtype, zeros_last, zeros_seed, ones_last, ones_seed) in EDGE_VECTORS {
+        let checksum_bits = mtype.checksum_bits() as u32;
+
+        for &(byte, filler, last, seed_hex) in &[
+            (0x00, "abandon", zeros_last, zeros_seed),
+            (0xff, "zoo", ones_last, ones_seed),
+        ] {
+            let entropy = vec![byte; mtype.entropy_bytes()];
+            let mut words = vec![filler; mtype.word_count() - 1];
+            words.push(last);
+            let phrase = words.join(" ");
+
+            test_mnemonic(&hex::encode(&entropy), &phrase);
+            test_seed(&phrase, "TREZOR", seed_hex);
+
+            let mnemonic = Mnemonic::from_phrase(&phrase, Language::English).unwrap();
+            assert_eq!(mnemonic.entropy(), &entropy[..]);
+
+            // the last word is the remaining entropy bits, all equal to the others, followed
+            // by the checksum
+            let (index, checksum, bits) = mnemonic.last_word_bit_split();
+            let entropy_bits = if byte == 0 { 0 } else { (1 << (11 - checksum_bits)) - 1 };
+            assert_eq!(bits, checksum_bits as usize);
+            assert_eq!(index, entropy_bits << checksum_bits | u16::from(checksum));
+            assert_eq!(checksum, Sha256::digest(&entropy)[0] >> (8 - checksum_bits));
+        }
+    }
+}
+
+#[test]
+fn test_checksum_bit_widths() {
+    let expected = [
+        (MnemonicType::Words12, 4),
+        (MnemonicType::Words15, 5),
+        (MnemonicType::Words18, 6),
+        (MnemonicType::Words21, 7),
+        (MnemonicType::Words24, 8),
+    ];
+
+    for &(mtype, checksum_bits) in &expected {
+        assert_eq!(mtype.checksum_bits(), checksum_bits);
+
+        for &byte in &[0x00, 0x5a, 0xa5, 0xff] {
+            let entropy = vec![byte; mtype.entropy_bits() / 8];
+            let mnemonic = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+
+            assert_eq!(mnemonic.phrase().split(' ').count(), mtype.word_count());
+
+            // The checksum is the low bits of the last word
+            let last_word = mnemonic.phrase().split(' ').next_back().unwrap();
+            let last_index = Language::English.word_index(last_word).unwrap();
+            let actual = last_index & ((1 << checksum_bits) - 1);
+
+            let expected = Sha256::digest(&entropy)[0] >> (8 - checksum_bits);
+
+            assert_eq!(actual, expected as u16, "checksum for {:?}", mtype);
+        }
+    }
+}
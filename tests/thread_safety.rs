@@ -0,0 +1,63 @@
+//! Compile-time checks that the public types can be shared and sent between threads, whatever
+//! the enabled features
+use bip39::*;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn assert_send_sync_val<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn public_types_are_send_sync() {
+    assert_send_sync::<Mnemonic>();
+    assert_send_sync::<MnemonicBuilder>();
+    assert_send_sync::<MnemonicType>();
+    assert_send_sync::<SecurityLevel>();
+    assert_send_sync::<Language>();
+    assert_send_sync::<Seed>();
+    assert_send_sync::<GenericSeed<32>>();
+    assert_send_sync::<Exposed<'static, 64>>();
+    assert_send_sync::<SeedBuilder<'static>>();
+    assert_send_sync::<DerivationParams>();
+    assert_send_sync::<NormalizationForm>();
+    assert_send_sync::<EntropyHandle>();
+    assert_send_sync::<FixedSource>();
+    assert_send_sync::<Checksum>();
+    assert_send_sync::<ParityCheck>();
+    assert_send_sync::<MnemonicReport>();
+    assert_send_sync::<WordReport>();
+    assert_send_sync::<StrengthReport>();
+    assert_send_sync::<FuzzyResult>();
+    assert_send_sync::<FuzzyWord>();
+    assert_send_sync::<ErrorKind>();
+    assert_send_sync::<HexError>();
+    assert_send_sync::<failure::Error>();
+
+    #[cfg(feature = "dynamic-wordlists")]
+    assert_send_sync::<CustomLanguage>();
+}
+
+#[test]
+fn streaming_words_are_send_sync() {
+    let (handle, words) = Mnemonic::generate_streaming(MnemonicType::Words12, Language::English)
+        .unwrap();
+
+    assert_send_sync_val(&words);
+
+    // the words can be rendered on another thread
+    let phrase = std::thread::spawn(move || words.collect::<Vec<_>>().join(" "))
+        .join()
+        .unwrap();
+
+    assert_eq!(phrase, handle.finalize().phrase());
+}
+
+#[test]
+fn seed_derived_on_another_thread() {
+    let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    let expected = Seed::new(&mnemonic, "").unwrap();
+
+    let seed = std::thread::spawn(move || Seed::new(&mnemonic, "").unwrap()).join().unwrap();
+
+    assert_eq!(seed.as_bytes(), expected.as_bytes());
+}
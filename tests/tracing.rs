@@ -0,0 +1,84 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use bip39::{Language, Mnemonic, Seed, SeedBuilder};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Collects every field recorded on spans and events as `name=value` strings
+#[derive(Clone, Default)]
+struct Collector {
+    fields: Arc<Mutex<Vec<String>>>,
+}
+
+impl Visit for Collector {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes) -> Id {
+        span.record(&mut self.clone());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn tracing_logs_metadata_without_secrets() {
+    let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    let password = "correct horse";
+
+    let collector = Collector::default();
+
+    let seeds = tracing::subscriber::with_default(collector.clone(), || {
+        let mnemonic: Mnemonic = phrase.parse().unwrap();
+
+        (
+            Seed::new(&mnemonic, password).unwrap(),
+            SeedBuilder::new(&mnemonic).password(password).derive(),
+        )
+    });
+
+    let fields = collector.fields.lock().unwrap().join(" ");
+
+    assert!(fields.contains("language=English"));
+    assert!(fields.contains("word_count=12"));
+    assert!(fields.contains("normalization_applied=false"));
+    assert!(fields.contains("iterations=2048"));
+
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    let secrets = [
+        format!("{:x}", seeds.0),
+        mnemonic.entropy_hex(),
+        password.to_owned(),
+    ];
+
+    for secret in phrase.split(' ').map(String::from).chain(secrets.iter().cloned()) {
+        assert!(!fields.contains(&secret), "leaked {:?}", secret);
+    }
+}
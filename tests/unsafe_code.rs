@@ -0,0 +1,38 @@
+//! Keeps `unsafe` out of the crate: only the locked memory of the `mlock` feature may use it,
+//! everything else is covered by `#![forbid(unsafe_code)]`.
+
+use std::fs;
+use std::path::Path;
+
+/// The only source file allowed to contain unsafe code
+const UNSAFE_ALLOWED: &str = "locked.rs";
+
+#[test]
+fn unsafe_code_is_forbidden() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+    assert!(lib.contains("#![cfg_attr(not(feature = \"mlock\"), forbid(unsafe_code))]"));
+
+    for entry in fs::read_dir(&src).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_owned();
+
+        if !name.ends_with(".rs") || name == UNSAFE_ALLOWED {
+            continue;
+        }
+
+        let source = fs::read_to_string(&path).unwrap();
+        let unsafe_lines: Vec<&str> = source
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .filter(|line| {
+                ["unsafe {", "unsafe fn", "unsafe impl"]
+                    .iter()
+                    .any(|keyword| line.contains(keyword))
+            })
+            .collect();
+
+        assert!(unsafe_lines.is_empty(), "unsafe code in {}: {:?}", name, unsafe_lines);
+    }
+}
@@ -1,6 +1,6 @@
 extern crate bip39;
 
-use bip39::{Language, Mnemonic, MnemonicType};
+use bip39::{ErrorKind, Language, Mnemonic, MnemonicType};
 
 fn validate_language(lang: Language) {
     let types = &[
@@ -13,7 +13,7 @@ fn validate_language(lang: Language) {
 
     for mtype in types {
         for _ in 0..1000 {
-            let m1 = Mnemonic::new(*mtype, lang);
+            let m1 = Mnemonic::new(*mtype, lang).unwrap();
             let m2 = Mnemonic::from_phrase(m1.phrase(), lang).expect("Can create a Mnemonic");
 
             assert_eq!(m1.entropy(), m2.entropy());
@@ -83,21 +83,18 @@ fn validate_english() {
 
 #[test]
 #[cfg(feature = "chinese-simplified")]
-
 fn validate_chinese_simplified() {
     validate_language(Language::ChineseSimplified);
 }
 
 #[test]
 #[cfg(feature = "chinese-traditional")]
-
 fn validate_chinese_traditional() {
     validate_language(Language::ChineseTraditional);
 }
 
 #[test]
 #[cfg(feature = "french")]
-
 fn validate_french() {
     validate_language(Language::French);
 }
@@ -110,21 +107,49 @@ fn validate_italian() {
 
 #[test]
 #[cfg(feature = "japanese")]
-
 fn validate_japanese() {
     validate_language(Language::Japanese);
 }
 
 #[test]
 #[cfg(feature = "korean")]
-
 fn validate_korean() {
     validate_language(Language::Korean);
 }
 
 #[test]
 #[cfg(feature = "spanish")]
-
 fn validate_spanish() {
     validate_language(Language::Spanish);
 }
+
+fn error_kind(phrase: &str) -> ErrorKind {
+    Mnemonic::from_phrase(phrase, Language::English)
+        .unwrap_err()
+        .downcast::<ErrorKind>()
+        .unwrap()
+}
+
+#[test]
+fn validate_error_predicates() {
+    let bad_checksum =
+        error_kind("park remain person kitchen mule spell knee armed position rail grid abandon");
+    assert!(bad_checksum.is_checksum_error());
+    assert!(!bad_checksum.is_word_error());
+    assert!(!bad_checksum.is_length_error());
+
+    let bad_word =
+        error_kind("park remain person kitchen mule spell knee armed position rail grid ankel");
+    assert!(bad_word.is_word_error());
+    assert!(!bad_word.is_checksum_error());
+
+    let bad_length = error_kind("park remain person kitchen mule spell knee armed position rail");
+    assert!(bad_length.is_length_error());
+    assert!(!bad_length.is_word_error());
+
+    // outside the crate a match on the non exhaustive enum needs a wildcard arm
+    match bad_length {
+        ErrorKind::InvalidWordLength(count) => assert_eq!(count, 10),
+        _ => panic!("unexpected error"),
+    }
+}